@@ -0,0 +1,74 @@
+use aoc::bits::{hexes_to_bits, Decoder, Lexer};
+use aoc::gen::{bits_to_hexes, deep_packet_bits, packet_bits};
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+const SEED: u64 = 2021;
+const SIZES: [usize; 4] = [10, 100, 1_000, 10_000];
+const DEPTHS: [usize; 3] = [10, 100, 1_000];
+
+fn random_transmission(n_literals: usize) -> Vec<u8> {
+    let mut rng = StdRng::seed_from_u64(SEED);
+
+    bits_to_hexes(packet_bits(&mut rng, n_literals))
+}
+
+fn deep_transmission(depth: usize) -> Vec<u8> {
+    let mut rng = StdRng::seed_from_u64(SEED);
+
+    bits_to_hexes(deep_packet_bits(&mut rng, depth))
+}
+
+fn transmissions() -> Vec<(String, Vec<u8>)> {
+    let random = SIZES
+        .into_iter()
+        .map(|n| (format!("random/{}", n), random_transmission(n)));
+    let deep = DEPTHS
+        .into_iter()
+        .map(|depth| (format!("deep/{}", depth), deep_transmission(depth)));
+
+    random.chain(deep).collect()
+}
+
+fn bench_hexes_to_bits(c: &mut Criterion) {
+    let mut group = c.benchmark_group("hexes_to_bits");
+
+    for (name, hexes) in transmissions() {
+        group.throughput(Throughput::Bytes(hexes.len() as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(name), &hexes, |b, hexes| {
+            b.iter(|| hexes_to_bits(black_box(hexes).iter().copied()).count())
+        });
+    }
+
+    group.finish();
+}
+
+fn bench_lexer(c: &mut Criterion) {
+    let mut group = c.benchmark_group("lexer");
+
+    for (name, hexes) in transmissions() {
+        group.throughput(Throughput::Bytes(hexes.len() as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(name), &hexes, |b, hexes| {
+            b.iter(|| Lexer::from_bits(hexes_to_bits(black_box(hexes).iter().copied())).count())
+        });
+    }
+
+    group.finish();
+}
+
+fn bench_decoder(c: &mut Criterion) {
+    let mut group = c.benchmark_group("decoder");
+
+    for (name, hexes) in transmissions() {
+        group.throughput(Throughput::Bytes(hexes.len() as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(name), &hexes, |b, hexes| {
+            b.iter(|| Decoder::from_bits(hexes_to_bits(black_box(hexes).iter().copied())).decode())
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_hexes_to_bits, bench_lexer, bench_decoder);
+criterion_main!(benches);
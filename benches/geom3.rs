@@ -0,0 +1,66 @@
+use aoc::gen::scanner_reports;
+use aoc::geom3::{self, Fingerprint, Point};
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+const SEED: u64 = 2021;
+const OVERLAP: usize = 12;
+const BEACONS: [usize; 3] = [26, 100, 400];
+
+// two scanners sharing `OVERLAP` of their `beacons` beacons
+fn scanner_pair(beacons: usize) -> (Vec<Point>, Vec<Point>) {
+    let mut rng = StdRng::seed_from_u64(SEED);
+    let reports = scanner_reports(&mut rng, 2, beacons).unwrap();
+
+    let mut scanners = reports.split("\n\n").map(|report| {
+        report
+            .lines()
+            .skip(1)
+            .map(|line| {
+                let coords: Vec<i64> = line.split(',').map(|c| c.parse().unwrap()).collect();
+
+                Point::from_coords(coords.try_into().unwrap())
+            })
+            .collect()
+    });
+
+    (scanners.next().unwrap(), scanners.next().unwrap())
+}
+
+fn bench_fingerprint(c: &mut Criterion) {
+    let mut group = c.benchmark_group("fingerprint");
+
+    for n in BEACONS {
+        let (points, _) = scanner_pair(n);
+
+        group.throughput(Throughput::Elements(n as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(n), &points, |b, points| {
+            b.iter(|| Fingerprint::new(black_box(points)))
+        });
+    }
+
+    group.finish();
+}
+
+fn bench_align(c: &mut Criterion) {
+    let mut group = c.benchmark_group("align");
+
+    for n in BEACONS {
+        let pair = scanner_pair(n);
+
+        group.throughput(Throughput::Elements(n as u64));
+        group.bench_with_input(
+            BenchmarkId::from_parameter(n),
+            &pair,
+            |b, (reference, points)| {
+                b.iter(|| geom3::align(black_box(reference), black_box(points), OVERLAP).unwrap())
+            },
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_fingerprint, bench_align);
+criterion_main!(benches);
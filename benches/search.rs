@@ -0,0 +1,85 @@
+use aoc::gen::digit_grid;
+use aoc::grid::{self, Grid};
+use aoc::search::{self, GridCosts};
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+const SEED: u64 = 2021;
+const SIZES: [usize; 3] = [10, 100, 500];
+
+fn risk_map(size: usize) -> Grid<u32> {
+    let mut rng = StdRng::seed_from_u64(SEED);
+    let lines: Vec<_> = digit_grid(&mut rng, size, size, 1)
+        .lines()
+        .map(String::from)
+        .collect();
+
+    grid::parse_digits(&lines).unwrap()
+}
+
+fn bench_dijkstra(c: &mut Criterion) {
+    let mut group = c.benchmark_group("dijkstra");
+    group.sample_size(10);
+
+    for size in SIZES {
+        let map = risk_map(size);
+        let end = (size - 1, size - 1);
+
+        group.throughput(Throughput::Elements((size * size) as u64));
+
+        group.bench_with_input(BenchmarkId::new("hash_map", size), &map, |b, map| {
+            b.iter(|| {
+                search::dijkstra(
+                    [black_box((0, 0))],
+                    |&pos| map.neighbors4(pos).map(|n| (n, map[n])),
+                    |&pos| pos == end,
+                )
+                .map(|path| path.cost)
+            })
+        });
+
+        group.bench_with_input(BenchmarkId::new("grid", size), &map, |b, map| {
+            b.iter(|| {
+                search::dijkstra_in(
+                    GridCosts::new(size, size),
+                    [black_box((0, 0))],
+                    |&pos| map.neighbors4(pos).map(|n| (n, map[n])),
+                    |&pos| pos == end,
+                )
+                .map(|path| path.cost)
+            })
+        });
+    }
+
+    group.finish();
+}
+
+fn bench_astar(c: &mut Criterion) {
+    let mut group = c.benchmark_group("astar");
+    group.sample_size(10);
+
+    for size in SIZES {
+        let map = risk_map(size);
+        let end = (size - 1, size - 1);
+
+        group.throughput(Throughput::Elements((size * size) as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(size), &map, |b, map| {
+            b.iter(|| {
+                search::astar_in(
+                    GridCosts::new(size, size),
+                    [black_box((0, 0))],
+                    |&pos| map.neighbors4(pos).map(|n| (n, map[n])),
+                    |&(x, y)| ((end.0 - x) + (end.1 - y)) as u32,
+                    |&pos| pos == end,
+                )
+                .map(|path| path.cost)
+            })
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_dijkstra, bench_astar);
+criterion_main!(benches);
@@ -0,0 +1,61 @@
+use aoc::gen::snailfish_numbers;
+use aoc::snailfish::Number;
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+const SEED: u64 = 2021;
+const SIZES: [usize; 3] = [10, 100, 1_000];
+
+fn numbers(n: usize) -> Vec<String> {
+    let mut rng = StdRng::seed_from_u64(SEED);
+
+    snailfish_numbers(&mut rng, n)
+        .lines()
+        .map(String::from)
+        .collect()
+}
+
+fn bench_parse(c: &mut Criterion) {
+    let mut group = c.benchmark_group("parse");
+
+    for n in SIZES {
+        let lines = numbers(n);
+
+        group.throughput(Throughput::Elements(n as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(n), &lines, |b, lines| {
+            b.iter(|| {
+                black_box(lines)
+                    .iter()
+                    .map(|line| line.parse().unwrap())
+                    .collect::<Vec<Number>>()
+            })
+        });
+    }
+
+    group.finish();
+}
+
+fn bench_sum(c: &mut Criterion) {
+    let mut group = c.benchmark_group("sum");
+
+    for n in SIZES {
+        let numbers: Vec<Number> = numbers(n).iter().map(|l| l.parse().unwrap()).collect();
+
+        group.throughput(Throughput::Elements(n as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(n), &numbers, |b, numbers| {
+            b.iter(|| {
+                black_box(numbers)
+                    .iter()
+                    .cloned()
+                    .reduce(|sum, number| sum + number)
+                    .map(|sum| sum.magnitude())
+            })
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_parse, bench_sum);
+criterion_main!(benches);
@@ -0,0 +1,50 @@
+use aoc::gen::commands;
+use aoc::submarine::{self, Mode};
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+const SEED: u64 = 2021;
+const SIZES: [usize; 3] = [1_000, 10_000, 100_000];
+
+fn program_lines(n: usize) -> Vec<String> {
+    let mut rng = StdRng::seed_from_u64(SEED);
+
+    commands(&mut rng, n).lines().map(String::from).collect()
+}
+
+fn bench_parse_program(c: &mut Criterion) {
+    let mut group = c.benchmark_group("parse_program");
+
+    for n in SIZES {
+        let lines = program_lines(n);
+
+        group.throughput(Throughput::Elements(n as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(n), &lines, |b, lines| {
+            b.iter(|| submarine::parse_program(black_box(lines), false).unwrap())
+        });
+    }
+
+    group.finish();
+}
+
+fn bench_run(c: &mut Criterion) {
+    let mut group = c.benchmark_group("run");
+
+    for n in SIZES {
+        let program = submarine::parse_program(&program_lines(n), false).unwrap();
+
+        group.throughput(Throughput::Elements(n as u64));
+
+        for (name, mode) in [("direct", Mode::Direct), ("aimed", Mode::Aimed)] {
+            group.bench_with_input(BenchmarkId::new(name, n), &program, |b, program| {
+                b.iter(|| submarine::run(black_box(program), mode))
+            });
+        }
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_parse_program, bench_run);
+criterion_main!(benches);
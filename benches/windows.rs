@@ -0,0 +1,41 @@
+use aoc::gen::sonar_depths;
+use aoc::windows;
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+const SEED: u64 = 2021;
+const SIZES: [usize; 3] = [1_000, 10_000, 100_000];
+const WINDOWS: [usize; 3] = [1, 3, 100];
+
+fn depths(n: usize) -> Vec<i64> {
+    let mut rng = StdRng::seed_from_u64(SEED);
+
+    sonar_depths(&mut rng, n)
+        .lines()
+        .map(|line| line.parse().unwrap())
+        .collect()
+}
+
+fn bench_analyze(c: &mut Criterion) {
+    let mut group = c.benchmark_group("analyze");
+
+    for n in SIZES {
+        let depths = depths(n);
+
+        group.throughput(Throughput::Elements(n as u64));
+
+        for size in WINDOWS {
+            let id = BenchmarkId::new(format!("window/{}", size), n);
+
+            group.bench_with_input(id, &depths, |b, depths| {
+                b.iter(|| windows::analyze(black_box(depths).iter().copied(), size))
+            });
+        }
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_analyze);
+criterion_main!(benches);
@@ -0,0 +1,67 @@
+use std::fs;
+use std::io::{self, Write};
+use std::path::PathBuf;
+
+use aoc::gen::{self, GENERATORS};
+use aoc::params::Params;
+use clap::Args;
+
+#[derive(Args)]
+pub struct GenArgs {
+    /// Day whose input is generated
+    #[clap(required_unless_present = "list")]
+    day: Option<u32>,
+
+    /// Seed of the random number generator
+    #[clap(long, default_value_t = 2021)]
+    seed: u64,
+
+    /// Overrides a size knob, e.g. `--param lines=100000`
+    #[clap(long = "param", value_name = "KEY=VALUE")]
+    params: Vec<String>,
+
+    /// Write the input to a file instead of stdout
+    #[clap(short, long, parse(from_os_str))]
+    output: Option<PathBuf>,
+
+    /// List the available generators along with their knobs
+    #[clap(long, conflicts_with = "day")]
+    list: bool,
+}
+
+fn invalid_input(msg: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, msg)
+}
+
+fn list() {
+    for generator in GENERATORS {
+        println!("day {:02}: {}", generator.day, generator.format);
+
+        for knob in generator.knobs() {
+            println!("    {}", knob.describe());
+        }
+    }
+}
+
+pub fn run(args: GenArgs) -> io::Result<()> {
+    let day = match args.day {
+        Some(day) if !args.list => day,
+        _ => {
+            list();
+            return Ok(());
+        }
+    };
+
+    let generator = gen::for_day(day)
+        .ok_or_else(|| invalid_input(format!("There is no input generator for day {}", day)))?;
+    let params = Params::resolve(&generator.knobs(), args.params.iter().map(String::as_str))
+        .map_err(|e| invalid_input(e.to_string()))?;
+    let input = generator
+        .generate(args.seed, &params)
+        .map_err(invalid_input)?;
+
+    match args.output {
+        Some(path) => fs::write(path, input),
+        None => io::stdout().lock().write_all(input.as_bytes()),
+    }
+}
@@ -0,0 +1,39 @@
+mod gen;
+mod new;
+mod perf;
+mod run;
+mod solutions;
+
+use clap::{Parser, Subcommand};
+
+/// Runner and tooling for the AOC 2021 solutions.
+#[derive(Parser)]
+#[clap(name = "aoc")]
+struct Cli {
+    #[clap(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Generate a random puzzle input of a day
+    Gen(gen::GenArgs),
+    /// Scaffold the solution of a new day from a template
+    New(new::NewArgs),
+    /// Time solutions and compare them against a saved baseline
+    Perf(perf::PerfArgs),
+    /// Run solutions concurrently and collect their answers
+    Run(run::RunArgs),
+}
+
+#[anyhoo::anyhoo]
+fn main() {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::Gen(args) => gen::run(args)?,
+        Command::New(args) => new::run(args)?,
+        Command::Perf(args) => perf::run(args)?,
+        Command::Run(args) => run::run(args)?,
+    }
+}
@@ -0,0 +1,298 @@
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use clap::Args;
+
+const TEMPLATE: &str = include_str!("templates/day.rs.tmpl");
+const SOLUTIONS_START: &str = "pub const SOLUTIONS: &[&str] = &[\n";
+const MAX_LINE_WIDTH: usize = 100;
+
+#[derive(Args)]
+pub struct NewArgs {
+    /// Day of the puzzle, 1 to 25
+    day: u32,
+
+    /// Title of the puzzle, e.g. "Trick Shot"
+    #[clap(long)]
+    title: String,
+}
+
+fn solution_name(day: u32) -> String {
+    format!("d{:02}", day)
+}
+
+fn render(day: u32, title: &str) -> String {
+    TEMPLATE
+        .replace("{day}", &day.to_string())
+        .replace("{input_file}", &aoc::input::file_name(day))
+        .replace("{title}", &title.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Finds the project root, i.e. the closest directory holding a `Cargo.toml`,
+/// starting from the current directory.
+fn find_root() -> io::Result<PathBuf> {
+    let cwd = env::current_dir()?;
+
+    cwd.ancestors()
+        .find(|dir| dir.join("Cargo.toml").is_file())
+        .map(Path::to_path_buf)
+        .ok_or_else(|| {
+            let msg = format!("No Cargo.toml in {} or its parents", cwd.display());
+            io::Error::new(io::ErrorKind::NotFound, msg)
+        })
+}
+
+/// Adds a `[[bin]]` entry for the solution to the manifest, keeping them sorted by name.
+///
+/// A solution that already has an entry is left as is.
+fn register_bin(manifest: &str, name: &str) -> io::Result<String> {
+    let mut result = manifest.to_string();
+
+    if result.contains(&format!("[[bin]]\nname = \"{}\"\n", name)) {
+        return Ok(result);
+    }
+
+    let entry = format!(
+        "[[bin]]\nname = \"{0}\"\npath = \"src/days/{0}.rs\"\n\n",
+        name
+    );
+
+    // insert before the first solution sorting after this one, or after the last one
+    let insert_at = result
+        .match_indices("[[bin]]\nname = \"")
+        .find(|&(i, tag)| {
+            let existing = &result[i + tag.len()..];
+            let existing = &existing[..existing.find('"').unwrap_or(existing.len())];
+
+            existing.starts_with('d') && existing > name
+        })
+        .map(|(i, _)| i)
+        .or_else(|| result.find("[dependencies]"))
+        .ok_or_else(|| io::Error::other("No [dependencies] section in Cargo.toml"))?;
+
+    result.insert_str(insert_at, &entry);
+
+    Ok(result)
+}
+
+/// Adds the solution to the runner's `SOLUTIONS` list, keeping it sorted.
+fn register_solution(source: &str, name: &str) -> io::Result<String> {
+    let start = source
+        .find(SOLUTIONS_START)
+        .map(|i| i + SOLUTIONS_START.len())
+        .ok_or_else(|| io::Error::other("No SOLUTIONS list in solutions.rs"))?;
+    let end = start
+        + source[start..]
+            .find("];")
+            .ok_or_else(|| io::Error::other("Unterminated SOLUTIONS list in solutions.rs"))?;
+
+    let mut solutions: Vec<_> = source[start..end]
+        .split(',')
+        .map(|s| s.trim().trim_matches('"'))
+        .filter(|s| !s.is_empty())
+        .map(str::to_string)
+        .chain([name.to_string()])
+        .collect();
+    solutions.sort_unstable();
+    solutions.dedup();
+
+    // laid out the way rustfmt does, filling each line
+    let mut list = String::new();
+    let mut line = String::new();
+
+    for solution in solutions {
+        let item = format!("\"{}\",", solution);
+
+        if !line.is_empty() && line.len() + 1 + item.len() > MAX_LINE_WIDTH {
+            list.push_str(&line);
+            list.push('\n');
+            line.clear();
+        }
+
+        line.push_str(if line.is_empty() { "    " } else { " " });
+        line.push_str(&item);
+    }
+
+    list.push_str(&line);
+    list.push('\n');
+
+    Ok(format!("{}{}{}", &source[..start], list, &source[end..]))
+}
+
+/// Returns where the contents of `path` are staged before replacing it.
+fn staging_path(path: &Path) -> PathBuf {
+    let name = path.file_name().unwrap_or_default().to_string_lossy();
+
+    path.with_file_name(format!(".{}.new", name))
+}
+
+/// Writes all files or none of them: each is first staged next to its destination,
+/// and only once all of them are staged are they moved into place.
+fn write_all(files: &[(PathBuf, String)]) -> io::Result<()> {
+    let staged: Vec<_> = files.iter().map(|(path, _)| staging_path(path)).collect();
+
+    let result = files
+        .iter()
+        .zip(&staged)
+        .try_for_each(|((_, contents), staging)| fs::write(staging, contents));
+
+    if let Err(e) = result {
+        for staging in &staged {
+            let _ = fs::remove_file(staging);
+        }
+
+        return Err(e);
+    }
+
+    // renames within a directory don't fail short of the directory going away
+    files
+        .iter()
+        .zip(&staged)
+        .try_for_each(|((path, _), staging)| fs::rename(staging, path))
+}
+
+pub fn run(args: NewArgs) -> io::Result<()> {
+    if !(1..=25).contains(&args.day) {
+        let msg = format!("There is no day {} in the calendar", args.day);
+        return Err(io::Error::new(io::ErrorKind::InvalidInput, msg));
+    }
+
+    let root = find_root()?;
+    let name = solution_name(args.day);
+    let source = root.join("src/days").join(&name).with_extension("rs");
+
+    if source.exists() {
+        let msg = format!("{} already exists", source.display());
+        return Err(io::Error::new(io::ErrorKind::AlreadyExists, msg));
+    }
+
+    let manifest_path = root.join("Cargo.toml");
+    let manifest = register_bin(&fs::read_to_string(&manifest_path)?, &name)?;
+    let runner_path = root.join("src/aoc/solutions.rs");
+    let runner = register_solution(&fs::read_to_string(&runner_path)?, &name)?;
+
+    let example = root
+        .join("input/examples")
+        .join(aoc::input::file_name(args.day));
+    let create_example = !example.exists();
+
+    let mut files = vec![
+        (source.clone(), render(args.day, &args.title)),
+        (manifest_path, manifest),
+        (runner_path, runner),
+    ];
+
+    if create_example {
+        fs::create_dir_all(example.parent().unwrap_or(&root))?;
+        files.push((example.clone(), String::new()));
+    }
+
+    write_all(&files)?;
+
+    println!("Created {}", source.display());
+    println!("Registered {} in Cargo.toml and the runner", name);
+
+    if create_example {
+        println!("Created {} for the puzzle's example", example.display());
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MANIFEST: &str = concat!(
+        "[package]\nname = \"aoc2021-rust\"\n\n",
+        "[[bin]]\nname = \"aoc\"\npath = \"src/aoc/main.rs\"\n\n",
+        "[[bin]]\nname = \"d01\"\npath = \"src/days/d01.rs\"\n\n",
+        "[[bin]]\nname = \"d03\"\npath = \"src/days/d03.rs\"\n\n",
+        "[dependencies]\nitertools = \"0.10\"\n",
+    );
+
+    fn bin_names(manifest: &str) -> Vec<&str> {
+        manifest
+            .lines()
+            .filter_map(|line| line.strip_prefix("name = \""))
+            .map(|name| name.trim_end_matches('"'))
+            .collect()
+    }
+
+    #[test]
+    fn bins_stay_sorted() {
+        let manifest = register_bin(MANIFEST, "d02").unwrap();
+        assert_eq!(
+            bin_names(&manifest),
+            ["aoc2021-rust", "aoc", "d01", "d02", "d03"]
+        );
+        assert!(manifest.contains("name = \"d02\"\npath = \"src/days/d02.rs\"\n\n[[bin]]"));
+
+        let manifest = register_bin(&manifest, "d25").unwrap();
+        assert!(manifest.contains("path = \"src/days/d25.rs\"\n\n[dependencies]"));
+    }
+
+    #[test]
+    fn registered_bins_are_kept() {
+        assert_eq!(register_bin(MANIFEST, "d03").unwrap(), MANIFEST);
+    }
+
+    #[test]
+    fn bins_need_a_place_to_go() {
+        let manifest = "[package]\nname = \"aoc2021-rust\"\n";
+
+        assert!(register_bin(manifest, "d01").is_err());
+    }
+
+    #[test]
+    fn solutions_stay_sorted() {
+        let source = "pub const SOLUTIONS: &[&str] = &[\n    \"d01\", \"d03\",\n];\n";
+        let registered = register_solution(source, "d02").unwrap();
+
+        assert_eq!(
+            registered,
+            "pub const SOLUTIONS: &[&str] = &[\n    \"d01\", \"d02\", \"d03\",\n];\n"
+        );
+        assert_eq!(register_solution(&registered, "d03").unwrap(), registered);
+    }
+
+    #[test]
+    fn long_solution_lists_wrap_like_rustfmt() {
+        let empty = format!("{}];\n", SOLUTIONS_START);
+        let source = (1..=25).fold(empty, |source, day| {
+            register_solution(&source, &solution_name(day)).unwrap()
+        });
+
+        assert!(include_str!("solutions.rs").contains(&source));
+    }
+
+    #[test]
+    fn malformed_solution_lists_are_rejected() {
+        let unterminated = format!("{}    \"d01\",\n", SOLUTIONS_START);
+
+        assert!(register_solution("pub const OTHERS: &[&str] = &[];\n", "d01").is_err());
+        assert!(register_solution(&unterminated, "d02").is_err());
+    }
+
+    #[test]
+    fn writes_all_files_or_none() {
+        let dir = env::temp_dir().join(format!("aoc-new-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+
+        let (a, b) = (dir.join("a.txt"), dir.join("b.txt"));
+        let missing = dir.join("missing/c.txt");
+
+        let result = write_all(&[(a.clone(), "a".into()), (missing, "c".into())]);
+        assert!(result.is_err());
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 0);
+
+        write_all(&[(a.clone(), "a".into()), (b.clone(), "b".into())]).unwrap();
+        assert_eq!(fs::read_to_string(&a).unwrap(), "a");
+        assert_eq!(fs::read_to_string(&b).unwrap(), "b");
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 2);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
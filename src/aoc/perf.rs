@@ -0,0 +1,165 @@
+use std::collections::BTreeMap;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use aoc::perf::{median, Timings};
+use clap::Args;
+
+use crate::solutions::{self, SOLUTIONS};
+
+#[derive(Args)]
+pub struct PerfArgs {
+    /// Record the measured timings in the baseline instead of comparing,
+    /// keeping those of the parts that were not timed
+    #[clap(long)]
+    save: bool,
+
+    /// Baseline timing file
+    #[clap(long, parse(from_os_str), default_value = "perf-baseline.txt")]
+    baseline: PathBuf,
+
+    /// Maximum allowed slowdown of the median solve time of a part, in percent
+    #[clap(long, default_value_t = 20.0)]
+    threshold: f64,
+
+    /// Number of timed runs per solution
+    #[clap(long, default_value_t = 5)]
+    runs: usize,
+
+    /// Directory holding the `dayNN.txt` puzzle inputs [default: $AOC_INPUT_DIR or input]
+    #[clap(long, parse(from_os_str))]
+    input_dir: Option<PathBuf>,
+
+    /// Solutions to time (all of them by default)
+    solutions: Vec<String>,
+}
+
+/// Returns the median solve time of every part of a solution, keyed by e.g. `d15p2`.
+fn time_solution(name: &str, input: &Path, runs: usize) -> io::Result<Vec<(String, Duration)>> {
+    let mut samples: BTreeMap<u32, Vec<Duration>> = BTreeMap::new();
+
+    for _ in 0..runs {
+        let records = solutions::run(name, input).map_err(io::Error::other)?;
+
+        for record in records {
+            samples
+                .entry(record.part)
+                .or_default()
+                .push(record.solve_time);
+        }
+    }
+
+    Ok(samples
+        .into_iter()
+        .map(|(part, mut samples)| (format!("{}p{}", name, part), median(&mut samples)))
+        .collect())
+}
+
+pub fn run(args: PerfArgs) -> io::Result<()> {
+    let selected: Vec<&str> = if args.solutions.is_empty() {
+        SOLUTIONS.to_vec()
+    } else {
+        args.solutions.iter().map(String::as_str).collect()
+    };
+
+    let runs = std::cmp::max(args.runs, 1);
+    let mut current = Timings::new();
+    let mut failures = 0;
+
+    for name in selected {
+        let (_, input) = solutions::resolve(name, args.input_dir.as_deref())?;
+
+        // a failing solution is reported, without holding up the timing of the others
+        match time_solution(name, &input, runs) {
+            Ok(timings) => {
+                for (part, timing) in timings {
+                    current.insert(part, timing);
+                }
+            }
+            Err(e) => {
+                failures += 1;
+                println!("{:<6} FAILED: {}", name, e);
+            }
+        }
+    }
+
+    if args.save {
+        // parts that were not timed keep their previous baseline
+        let mut baseline = match Timings::load(&args.baseline) {
+            Ok(baseline) => baseline,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Timings::new(),
+            Err(e) => return Err(e),
+        };
+
+        for (name, timing) in current.iter() {
+            println!("{:<6} {:>12.3?}", name, timing);
+            baseline.insert(name, timing);
+        }
+
+        baseline.save(&args.baseline)?;
+
+        println!("Baseline saved to {}", args.baseline.display());
+
+        if failures > 0 {
+            eprintln!("{} solution(s) failed and were not saved", failures);
+            std::process::exit(1);
+        }
+    } else {
+        let baseline = Timings::load(&args.baseline)?;
+        let mut regressions = 0;
+
+        for cmp in current.compare(&baseline) {
+            let verdict = if cmp.is_regression(args.threshold) {
+                regressions += 1;
+                "REGRESSED"
+            } else {
+                "ok"
+            };
+
+            println!(
+                "{:<6} {:>12.3?} -> {:>12.3?} {:>+8.1}% {}",
+                cmp.name,
+                cmp.baseline,
+                cmp.current,
+                cmp.change_percent(),
+                verdict
+            );
+        }
+
+        let missing = current.missing_from(&baseline);
+
+        for name in &missing {
+            let timing = current.get(name).unwrap_or_default();
+
+            println!(
+                "{:<6} {:>12} -> {:>12.3?} MISSING FROM BASELINE",
+                name, "-", timing
+            );
+        }
+
+        if regressions > 0 {
+            eprintln!(
+                "{} part(s) slower than the baseline by more than {}%",
+                regressions, args.threshold
+            );
+        }
+
+        if !missing.is_empty() {
+            eprintln!(
+                "{} part(s) missing from the baseline, record them with --save",
+                missing.len()
+            );
+        }
+
+        if failures > 0 {
+            eprintln!("{} solution(s) failed", failures);
+        }
+
+        if regressions > 0 || !missing.is_empty() || failures > 0 {
+            std::process::exit(1);
+        }
+    }
+
+    Ok(())
+}
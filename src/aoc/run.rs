@@ -0,0 +1,321 @@
+use std::io;
+use std::num::NonZeroUsize;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use aoc::report::Record;
+use clap::Args;
+
+use crate::solutions::{self, SOLUTIONS};
+
+#[derive(Args)]
+pub struct RunArgs {
+    /// Run every solution
+    #[clap(long)]
+    all: bool,
+
+    /// Number of solutions run concurrently [default: number of CPUs]
+    #[clap(short, long)]
+    jobs: Option<usize>,
+
+    /// Afterwards rerun the solutions one at a time and report those timings,
+    /// undisturbed by other solutions competing for the CPU
+    #[clap(long)]
+    serial_timing: bool,
+
+    /// Directory holding the `dayNN.txt` puzzle inputs [default: $AOC_INPUT_DIR or input]
+    #[clap(long, parse(from_os_str))]
+    input_dir: Option<PathBuf>,
+
+    /// Solutions to run
+    #[clap(required_unless_present = "all", conflicts_with = "all")]
+    solutions: Vec<String>,
+}
+
+struct Job<'a> {
+    name: &'a str,
+    input: PathBuf,
+}
+
+/// The records printed by a solution for each of its parts, or why there are none.
+type Outcome = Result<Vec<Record>, String>;
+
+/// Outcomes of running every job once, in job order.
+struct Pass {
+    outcomes: Vec<Outcome>,
+    wall_time: Duration,
+    cpu_time: Duration,
+}
+
+/// Runs the jobs on a pool of `workers` threads.
+fn run_pool(jobs: &[Job], workers: usize) -> Vec<Outcome> {
+    let next = AtomicUsize::new(0);
+    let (tx, rx) = mpsc::channel();
+
+    thread::scope(|s| {
+        for _ in 0..workers {
+            let (next, tx) = (&next, tx.clone());
+
+            s.spawn(move || loop {
+                let i = next.fetch_add(1, Ordering::Relaxed);
+                let job = match jobs.get(i) {
+                    Some(job) => job,
+                    None => break,
+                };
+
+                let outcome = solutions::run(job.name, &job.input);
+                tx.send((i, outcome)).unwrap();
+            });
+        }
+    });
+
+    drop(tx);
+
+    let mut outcomes: Vec<_> = rx.into_iter().collect();
+    outcomes.sort_unstable_by_key(|&(i, _)| i);
+
+    outcomes.into_iter().map(|(_, outcome)| outcome).collect()
+}
+
+fn run_pass(jobs: &[Job], workers: usize) -> Pass {
+    let cpu_before = children_cpu_time();
+    let start = Instant::now();
+
+    let outcomes = run_pool(jobs, workers);
+
+    let wall_time = start.elapsed();
+    let cpu_time = match (cpu_before, children_cpu_time()) {
+        (Some(before), Some(after)) => after.saturating_sub(before),
+        // fall back on the timings the solutions measured themselves, parsing once per solution
+        _ => outcomes
+            .iter()
+            .flatten()
+            .map(|records| {
+                let parse_time = records.first().map(|r| r.parse_time);
+
+                parse_time.unwrap_or_default() + records.iter().map(|r| r.solve_time).sum()
+            })
+            .sum(),
+    };
+
+    Pass {
+        outcomes,
+        wall_time,
+        cpu_time,
+    }
+}
+
+/// Total user and system CPU time of all terminated child processes.
+#[cfg(unix)]
+fn children_cpu_time() -> Option<Duration> {
+    let mut usage = std::mem::MaybeUninit::<libc::rusage>::zeroed();
+
+    // SAFETY: getrusage only writes into the provided struct
+    let usage = unsafe {
+        if libc::getrusage(libc::RUSAGE_CHILDREN, usage.as_mut_ptr()) != 0 {
+            return None;
+        }
+
+        usage.assume_init()
+    };
+
+    let duration = |t: libc::timeval| {
+        Duration::from_secs(t.tv_sec as u64) + Duration::from_micros(t.tv_usec as u64)
+    };
+
+    Some(duration(usage.ru_utime) + duration(usage.ru_stime))
+}
+
+#[cfg(not(unix))]
+fn children_cpu_time() -> Option<Duration> {
+    None
+}
+
+/// Formats the answers of a solution along with the timings it got in `timed`,
+/// returning the lines and the number of failures among them.
+fn describe(name: &str, outcome: &Outcome, timed: &Outcome) -> (Vec<String>, usize) {
+    match (outcome, timed) {
+        (Ok(records), Ok(timings)) => {
+            if records.len() != timings.len() {
+                return (
+                    vec![format!("{:<6} FAILED: PARTS CHANGED IN SERIAL PASS", name)],
+                    1,
+                );
+            }
+
+            let mut failures = 0;
+            let lines = records
+                .iter()
+                .zip(timings)
+                .map(|(record, timing)| {
+                    let verdict = if record.answer == timing.answer {
+                        ""
+                    } else {
+                        failures += 1;
+                        " ANSWER CHANGED IN SERIAL PASS"
+                    };
+
+                    format!(
+                        "{:<6} {:<20} parse {:>12.3?}  solve {:>12.3?}{}",
+                        format!("{}p{}", name, record.part),
+                        record.answer,
+                        timing.parse_time,
+                        timing.solve_time,
+                        verdict
+                    )
+                })
+                .collect();
+
+            (lines, failures)
+        }
+        (Err(reason), _) | (_, Err(reason)) => (vec![format!("{:<6} FAILED: {}", name, reason)], 1),
+    }
+}
+
+pub fn run(args: RunArgs) -> io::Result<()> {
+    let selected: Vec<&str> = if args.all {
+        SOLUTIONS.to_vec()
+    } else {
+        args.solutions.iter().map(String::as_str).collect()
+    };
+
+    let jobs: Vec<_> = selected
+        .into_iter()
+        .map(|name| {
+            let (_, input) = solutions::resolve(name, args.input_dir.as_deref())?;
+
+            Ok(Job { name, input })
+        })
+        .collect::<io::Result<_>>()?;
+
+    let workers = args
+        .jobs
+        .unwrap_or_else(|| thread::available_parallelism().map_or(1, NonZeroUsize::get));
+    let workers = workers.clamp(1, std::cmp::max(jobs.len(), 1));
+
+    let pass = run_pass(&jobs, workers);
+    let serial = args.serial_timing.then(|| run_pass(&jobs, 1));
+
+    let mut failures = 0;
+
+    for (i, job) in jobs.iter().enumerate() {
+        let timed = serial.as_ref().unwrap_or(&pass);
+        let (lines, failed) = describe(job.name, &pass.outcomes[i], &timed.outcomes[i]);
+
+        for line in lines {
+            println!("{}", line);
+        }
+        failures += failed;
+    }
+
+    println!();
+    println!(
+        "{} solution(s) on {} worker(s): wall time {:.3?}, CPU time {:.3?} ({:.1}x parallelism)",
+        jobs.len(),
+        workers,
+        pass.wall_time,
+        pass.cpu_time,
+        pass.cpu_time.as_secs_f64() / pass.wall_time.as_secs_f64().max(f64::EPSILON)
+    );
+
+    if let Some(serial) = &serial {
+        println!(
+            "serialized timing pass: wall time {:.3?}, CPU time {:.3?}",
+            serial.wall_time, serial.cpu_time
+        );
+    }
+
+    if failures > 0 {
+        eprintln!("{} solution(s) failed", failures);
+        std::process::exit(1);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(part: u32, answer: &str, solve_ms: u64) -> Record {
+        Record {
+            day: 1,
+            part,
+            answer: answer.to_string(),
+            parse_time: Duration::from_millis(1),
+            solve_time: Duration::from_millis(solve_ms),
+            input_path: "input/day01.txt".to_string(),
+        }
+    }
+
+    #[test]
+    fn pool_keeps_job_order() {
+        let names: Vec<_> = (0..10).map(|i| format!("missing{}", i)).collect();
+        let jobs: Vec<_> = names
+            .iter()
+            .map(|name| Job {
+                name,
+                input: PathBuf::from("nowhere.txt"),
+            })
+            .collect();
+
+        let outcomes = run_pool(&jobs, 3);
+
+        assert_eq!(outcomes.len(), jobs.len());
+        for (name, outcome) in names.iter().zip(outcomes) {
+            let reason = outcome.unwrap_err();
+
+            assert!(reason.starts_with("Failed to start"), "{}", reason);
+            assert!(reason.contains(name.as_str()), "{}", reason);
+        }
+    }
+
+    #[test]
+    fn reports_serial_timings() {
+        let pass = Ok(vec![record(1, "7", 5), record(2, "5", 6)]);
+        let serial = Ok(vec![record(1, "7", 3), record(2, "5", 4)]);
+
+        let (lines, failures) = describe("d01", &pass, &serial);
+
+        assert_eq!(failures, 0);
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].starts_with("d01p1  7 "), "{}", lines[0]);
+        assert!(lines[1].ends_with("solve      4.000ms"), "{}", lines[1]);
+    }
+
+    #[test]
+    fn fails_on_changed_answers_and_parts() {
+        let pass = Ok(vec![record(1, "7", 5), record(2, "5", 6)]);
+
+        let (lines, failures) = describe(
+            "d01",
+            &pass,
+            &Ok(vec![record(1, "7", 3), record(2, "6", 4)]),
+        );
+        assert_eq!(failures, 1);
+        assert!(!lines[0].contains("CHANGED"));
+        assert!(lines[1].ends_with("ANSWER CHANGED IN SERIAL PASS"));
+
+        let (lines, failures) = describe("d01", &pass, &Ok(vec![record(1, "7", 3)]));
+        assert_eq!(failures, 1);
+        assert_eq!(lines, ["d01    FAILED: PARTS CHANGED IN SERIAL PASS"]);
+    }
+
+    #[test]
+    fn reports_failed_runs() {
+        let failed = Err("exited with exit status: 1: Error: bad input".to_string());
+
+        for (pass, serial) in [(&failed, &Ok(vec![])), (&Ok(vec![]), &failed)] {
+            let (lines, failures) = describe("d02", pass, serial);
+
+            assert_eq!(failures, 1);
+            assert_eq!(
+                lines,
+                ["d02    FAILED: exited with exit status: 1: Error: bad input"]
+            );
+        }
+    }
+}
@@ -0,0 +1,69 @@
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+use aoc::input;
+use aoc::report::Record;
+
+/// Names of all solution binaries known to the runner.
+pub const SOLUTIONS: &[&str] = &[
+    "d01", "d02", "d03", "d04", "d05", "d06", "d07", "d08", "d09", "d10", "d11", "d12", "d13",
+    "d14", "d15", "d16", "d17", "d18", "d19", "d20", "d21", "d22", "d23", "d24", "d25",
+];
+
+/// Extracts the day number from a solution name like `d15`.
+pub fn day_of(solution: &str) -> Option<u32> {
+    solution.get(1..3)?.parse().ok()
+}
+
+/// Returns the path of a solution's executable, which is built next to the runner.
+pub fn solution_exe(name: &str) -> io::Result<PathBuf> {
+    let exe = std::env::current_exe()?;
+    let dir = exe.parent().unwrap_or_else(|| Path::new("."));
+
+    Ok(dir
+        .join(name)
+        .with_extension(std::env::consts::EXE_EXTENSION))
+}
+
+/// Resolves a solution's day and the input it is run on,
+/// looked up in `input_dir` or at the default location.
+pub fn resolve(name: &str, input_dir: Option<&Path>) -> io::Result<(u32, PathBuf)> {
+    let day = day_of(name).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("Unknown solution: {}", name),
+        )
+    })?;
+    let input = match input_dir {
+        Some(dir) => dir.join(input::file_name(day)),
+        None => input::default_path(day),
+    };
+
+    Ok((day, input))
+}
+
+/// Runs a solution on `input` with `--format json`, returning the record of each part.
+///
+/// Fails with the first line the solution printed to stderr if it does not succeed.
+pub fn run(name: &str, input: &Path) -> Result<Vec<Record>, String> {
+    let exe = solution_exe(name).map_err(|e| e.to_string())?;
+    let output = Command::new(&exe)
+        .arg(input)
+        .args(["--format", "json"])
+        .stdin(Stdio::null())
+        .output()
+        .map_err(|e| format!("Failed to start {}: {}", exe.display(), e))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let reason = stderr.lines().next().unwrap_or_default();
+
+        return Err(format!("exited with {}: {}", output.status, reason));
+    }
+
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .map(str::parse)
+        .collect()
+}
@@ -0,0 +1,168 @@
+use std::cmp::Ordering;
+use std::io::BufRead;
+
+use aoc::input::Input;
+use aoc::params::{Param, Params};
+use aoc::parse::{self, ParseError};
+use aoc::report;
+use aoc::windows::{self, Window, WindowAnalyzer, WindowStats};
+use itertools::Itertools;
+
+#[anyhoo::anyhoo]
+fn parse_input() -> (Input, Params) {
+    Input::parse_args_with(
+        1,
+        "Day 1: Sonar Sweep",
+        &[
+            Param::new::<usize>("window1", 1, "Number of depths summed per window in part 1")
+                .at_least(1),
+            Param::new::<usize>("window2", 3, "Number of depths summed per window in part 2")
+                .at_least(1),
+            Param::option::<i64>(
+                "threshold",
+                "SUM",
+                "Print the windows whose sum is above SUM to stderr",
+            ),
+            Param::switch(
+                "stats",
+                "Print increases, decreases and the longest increasing run to stderr",
+            ),
+            Param::switch(
+                "follow",
+                "Analyze depths as they arrive, printing every window instead of the answers",
+            ),
+        ],
+    )?
+}
+
+fn parse_depth(line: parse::Line) -> Result<i64, ParseError> {
+    line.parse(line.text())
+}
+
+fn describe_window(size: usize, window: Window) -> String {
+    let above = match window.above_threshold {
+        true => " (above threshold)",
+        false => "",
+    };
+
+    format!(
+        "window of {} from line {} sums to {}{}",
+        size,
+        window.start + 1,
+        window.sum,
+        above
+    )
+}
+
+fn describe_stats(size: usize, stats: WindowStats) -> String {
+    format!(
+        "Windows of {}: {} windows, {} increases, {} decreases, longest increasing run of {}",
+        size, stats.windows, stats.increases, stats.decreases, stats.longest_increasing_run
+    )
+}
+
+/// Prints the statistics of the windows if `stats` is set,
+/// and the windows above `threshold` if one is given.
+fn print_stats(depths: &[i64], size: usize, stats: bool, threshold: Option<i64>) {
+    if stats {
+        let stats = windows::analyze(depths.iter().copied(), size);
+
+        eprintln!("{}", describe_stats(size, stats));
+    }
+
+    if let Some(threshold) = threshold {
+        let above = windows::above_threshold(depths.iter().copied(), size, threshold);
+
+        eprintln!(
+            "Windows of {}: {} above {}, starting at lines: {}",
+            size,
+            above.len(),
+            threshold,
+            above.iter().map(|start| start + 1).join(", ")
+        );
+    }
+}
+
+/// Reads depths one line at a time for as long as the input lasts,
+/// printing every window as soon as it is complete.
+#[anyhoo::anyhoo]
+fn follow(input: Input, sizes: &[usize], threshold: Option<i64>) {
+    let mut analyzers = sizes
+        .iter()
+        .map(|&size| match threshold {
+            Some(threshold) => WindowAnalyzer::new(size).with_threshold(threshold),
+            None => WindowAnalyzer::new(size),
+        })
+        .collect_vec();
+
+    for (i, text) in input.lines().enumerate() {
+        let text = text?;
+        let text = text.strip_suffix('\r').unwrap_or(&text);
+        let depth = parse_depth(parse::Line::new(i + 1, text))?;
+
+        for analyzer in &mut analyzers {
+            let window = match analyzer.push(depth) {
+                Some(window) => window,
+                None => continue,
+            };
+            let change = match window.change {
+                Some(Ordering::Greater) => ", increased",
+                Some(Ordering::Less) => ", decreased",
+                Some(Ordering::Equal) => ", unchanged",
+                None => "",
+            };
+
+            println!(
+                "{}: {}{}",
+                i + 1,
+                describe_window(analyzer.size(), window),
+                change
+            );
+        }
+    }
+
+    for analyzer in &analyzers {
+        let stats = analyzer.stats();
+
+        println!("{}", describe_stats(analyzer.size(), stats));
+
+        if let Some(threshold) = threshold {
+            println!(
+                "Windows of {}: {} above {}",
+                analyzer.size(),
+                stats.above_threshold,
+                threshold
+            );
+        }
+    }
+}
+
+#[anyhoo::anyhoo]
+fn main() {
+    let (input, params) = parse_input()?;
+    let sizes = [params.get("window1"), params.get("window2")];
+    let threshold = params.get_option("threshold");
+
+    if params.get("follow") {
+        follow(input, &sizes, threshold)?;
+    } else {
+        let lines = input.read_lines()?;
+        let depths: Vec<_> = parse::lines(&lines).map(parse_depth).try_collect()?;
+
+        report::measure_and_print(1, || {
+            windows::analyze(depths.iter().copied(), sizes[0]).increases
+        });
+
+        report::measure_and_print(2, || {
+            windows::analyze(depths.iter().copied(), sizes[1]).increases
+        });
+
+        let stats = params.get("stats");
+
+        if stats || threshold.is_some() {
+            for size in sizes {
+                print_stats(&depths, size, stats, threshold);
+            }
+        }
+    }
+}
@@ -0,0 +1,83 @@
+use aoc::input::Input;
+use aoc::params::{Param, Params};
+use aoc::report;
+use aoc::submarine::{self, Command, Mode, OutOfRange};
+
+const PROFILE_WIDTH: usize = 100;
+const PROFILE_HEIGHT: usize = 20;
+
+#[anyhoo::anyhoo]
+fn parse_input() -> (Vec<Command>, Params) {
+    let (input, params) = Input::parse_args_with(
+        2,
+        "Day 2: Dive!",
+        &[
+            Param::switch(
+                "extended",
+                "Also accept back, reset-aim and repeat ... end blocks",
+            ),
+            Param::switch("trace", "Print every position of the submarine to stderr"),
+            Param::switch("profile", "Draw the depth profile of each part to stderr"),
+        ],
+    )?;
+
+    let lines = input.read_lines()?;
+    let extended = params.get("extended");
+
+    (submarine::parse_program(&lines, extended)?, params)
+}
+
+/// Multiplies the horizontal position by the depth the program ends at.
+fn final_position(program: &[Command], mode: Mode) -> Result<i64, OutOfRange> {
+    let end = submarine::run(program, mode)?;
+
+    end.horizontal.checked_mul(end.depth).ok_or(OutOfRange)
+}
+
+fn print_course(
+    part: u32,
+    program: &[Command],
+    mode: Mode,
+    positions: bool,
+    profile: bool,
+) -> Result<(), OutOfRange> {
+    eprintln!("Part {} course:", part);
+
+    if positions {
+        let mut step = 0;
+
+        submarine::trace(program, mode, |state| {
+            eprintln!(
+                "  {:>5}: horizontal {}, depth {}, aim {}",
+                step, state.horizontal, state.depth, state.aim
+            );
+            step += 1;
+        })?;
+    }
+
+    if profile {
+        eprintln!(
+            "{}",
+            submarine::render_profile(program, mode, PROFILE_WIDTH, PROFILE_HEIGHT)?
+        );
+    }
+
+    Ok(())
+}
+
+#[anyhoo::anyhoo]
+fn main() {
+    let (program, params) = parse_input()?;
+
+    report::try_measure_and_print(1, || final_position(&program, Mode::Direct))?;
+    report::try_measure_and_print(2, || final_position(&program, Mode::Aimed))?;
+
+    let positions: bool = params.get("trace");
+    let profile: bool = params.get("profile");
+
+    if positions || profile {
+        for (part, mode) in [(1, Mode::Direct), (2, Mode::Aimed)] {
+            print_course(part, &program, mode, positions, profile)?;
+        }
+    }
+}
@@ -0,0 +1,188 @@
+use std::error::Error;
+use std::fmt::{self, Display};
+
+use aoc::input::Input;
+use aoc::parse::{self, ParseError};
+use aoc::report;
+use itertools::Itertools;
+
+#[anyhoo::anyhoo]
+fn parse_input() -> Vec<Vec<u8>> {
+    let input = Input::parse_args(3, "Day 3: Binary Diagnostic")?;
+
+    let lines = input.read_lines()?;
+    let width = parse::first_line(&lines, "a binary number")?.text().len();
+
+    parse::lines(&lines)
+        .map(|line| parse_bits(line, width))
+        .try_collect()?
+}
+
+/// The widest numbers whose rates and ratings multiply without overflowing.
+const MAX_WIDTH: usize = 32;
+
+/// Parses a binary number, which must be `width` digits long like all the others.
+fn parse_bits(line: parse::Line, width: usize) -> Result<Vec<u8>, ParseError> {
+    if line.digits(2)?.is_empty() {
+        return Err(line.error(line.text(), "a binary number"));
+    }
+
+    if width > MAX_WIDTH {
+        return Err(line.mismatch(format!("a binary number of at most {} digits", MAX_WIDTH)));
+    }
+
+    if line.text().len() != width {
+        return Err(line.mismatch(format!("a binary number of {} digits", width)));
+    }
+
+    Ok(line.text().as_bytes().to_vec())
+}
+
+fn decode(occurs: &[usize], n: usize) -> (usize, usize) {
+    let half = n / 2;
+    let mut gamma = 0;
+    let mut epsilon = 0;
+
+    for (i, v) in occurs.iter().rev().enumerate() {
+        if v > &half {
+            gamma += 1 << i;
+        } else {
+            epsilon += 1 << i;
+        }
+    }
+
+    (gamma, epsilon)
+}
+
+fn pick<'a>(values: &[&'a [u8]], pos: usize, most_common: bool) -> Vec<&'a [u8]> {
+    let n_ones = values.iter().filter(|val| val[pos] == b'1').count();
+    let n_zeros = values.len() - n_ones;
+
+    // ties keep the ones for the most common bit and the zeros for the least common
+    let searched_bit = if most_common {
+        n_ones >= n_zeros
+    } else {
+        n_ones < n_zeros
+    };
+
+    values
+        .iter()
+        .filter(|v| {
+            let bit = v[pos] == b'1';
+
+            bit == searched_bit
+        })
+        .copied()
+        .collect()
+}
+
+/// Narrows the numbers down by their most or least common bits, one position at a time,
+/// returning `None` if that rules out every number.
+fn rating(values: &[Vec<u8>], most_common: bool) -> Option<usize> {
+    let mut candidates = values.iter().map(Vec::as_slice).collect_vec();
+    let width = candidates.first()?.len();
+
+    for pos in 0..width {
+        if candidates.len() <= 1 {
+            break;
+        }
+
+        candidates = pick(&candidates, pos, most_common);
+    }
+
+    // duplicates of the same number may be left over
+    candidates.first().map(|bin| to_dec(bin))
+}
+
+fn to_dec(bin: &[u8]) -> usize {
+    bin.iter()
+        .rev()
+        .enumerate()
+        .map(|(i, b)| ((b == &b'1') as usize) << i)
+        .sum()
+}
+
+/// The bit criteria rule out every number before a rating is found.
+#[derive(Clone, Copy, Debug)]
+struct NoRating(&'static str);
+
+impl Display for NoRating {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "No number is left to read the {} rating from", self.0)
+    }
+}
+
+impl Error for NoRating {}
+
+#[anyhoo::anyhoo]
+fn main() {
+    let input = parse_input()?;
+    let len = input[0].len(); // there is at least one number of this width
+
+    report::measure_and_print(1, || {
+        let mut occurs = vec![0; len];
+
+        for (i, &b) in input.iter().flat_map(|s| s.iter().enumerate()) {
+            if b == b'1' {
+                occurs[i] += 1;
+            }
+        }
+
+        let (gamma, epsilon) = decode(&occurs, input.len());
+
+        gamma * epsilon
+    });
+
+    report::try_measure_and_print(2, || -> Result<_, NoRating> {
+        let oxygen = rating(&input, true).ok_or(NoRating("oxygen generator"))?;
+        let co2 = rating(&input, false).ok_or(NoRating("CO2 scrubber"))?;
+
+        Ok(oxygen * co2)
+    })?;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: [&str; 12] = [
+        "00100", "11110", "10110", "10111", "10101", "01111", "00111", "11100", "10000", "11001",
+        "00010", "01010",
+    ];
+
+    fn parse(lines: &[&str]) -> Result<Vec<Vec<u8>>, ParseError> {
+        parse::lines(lines)
+            .map(|line| parse_bits(line, lines[0].len()))
+            .try_collect()
+    }
+
+    #[test]
+    fn example_ratings() {
+        let input = parse(&EXAMPLE).unwrap();
+
+        assert_eq!(rating(&input, true), Some(23));
+        assert_eq!(rating(&input, false), Some(10));
+    }
+
+    #[test]
+    fn ratings_running_out_of_numbers() {
+        let input = parse(&["10", "10"]).unwrap();
+        assert_eq!(rating(&input, true), Some(2));
+        assert_eq!(rating(&input, false), None);
+
+        let input = parse(&["01"]).unwrap();
+        assert_eq!(rating(&input, true), Some(1));
+        assert_eq!(rating(&input, false), Some(1));
+
+        let input = parse(&["11", "11", "01"]).unwrap();
+        assert_eq!(rating(&input, true), Some(3));
+        assert_eq!(rating(&input, false), Some(1));
+    }
+
+    #[test]
+    fn rejects_numbers_too_wide_to_multiply() {
+        let wide = "1".repeat(MAX_WIDTH + 1);
+
+        assert!(parse(&[&wide]).is_err());
+    }
+}
@@ -0,0 +1,154 @@
+use std::error::Error;
+use std::fmt::{self, Display};
+
+use aoc::input::Input;
+use aoc::parse::{self, Line, ParseError};
+use aoc::report;
+use itertools::Itertools;
+use ndarray::Array2;
+
+#[anyhoo::anyhoo]
+fn parse_input() -> (Vec<i32>, Vec<Board>) {
+    let input = Input::parse_args(4, "Day 4: Giant Squid")?;
+
+    let lines = input.read_lines()?;
+
+    let draws = parse::first_line(&lines, "comma-separated draws")?.parse_list(",")?;
+    let boards: Vec<_> = parse::sections(&lines)
+        .skip(1)
+        .map(|rows| parse_board(&rows))
+        .try_collect()?;
+
+    (draws, require_boards(boards, lines.len())?)
+}
+
+/// Fails if the input ends before the first board.
+fn require_boards(boards: Vec<Board>, n_lines: usize) -> Result<Vec<Board>, ParseError> {
+    match boards.is_empty() {
+        true => Err(ParseError::end_of_input(n_lines + 1, "a bingo board")),
+        false => Ok(boards),
+    }
+}
+
+const BOARD_SIZE: usize = 5;
+
+type Board = Array2<(i32, bool)>;
+
+fn parse_board(rows: &[Line]) -> Result<Board, ParseError> {
+    if rows.len() < BOARD_SIZE {
+        let last = rows.last().map_or(0, Line::number);
+
+        return Err(ParseError::new(
+            last + 1,
+            1,
+            "another board row",
+            "end of board",
+        ));
+    }
+
+    if let Some(extra) = rows.get(BOARD_SIZE) {
+        return Err(extra.mismatch("a blank line after 5 board rows"));
+    }
+
+    let mut cells = Vec::with_capacity(BOARD_SIZE * BOARD_SIZE);
+
+    for row in rows {
+        let numbers: Vec<i32> = row
+            .text()
+            .split_whitespace()
+            .map(|n| row.parse(n))
+            .try_collect()?;
+
+        if numbers.len() != BOARD_SIZE {
+            return Err(row.mismatch("a row of 5 numbers"));
+        }
+
+        cells.extend(numbers.into_iter().map(|n| (n, false)));
+    }
+
+    Ok(Board::from_shape_vec((BOARD_SIZE, BOARD_SIZE), cells).unwrap())
+}
+
+fn mark_board(board: &mut Board, draw: i32) -> Option<i32> {
+    for (n, marked) in board.iter_mut() {
+        if n == &draw {
+            *marked = true;
+        }
+    }
+
+    let row_found = || {
+        board
+            .rows()
+            .into_iter()
+            .any(|r| r.iter().all(|(_, marked)| *marked))
+    };
+    let col_found = || {
+        board
+            .columns()
+            .into_iter()
+            .any(|c| c.iter().all(|(_, marked)| *marked))
+    };
+
+    if !row_found() && !col_found() {
+        return None;
+    }
+
+    let lane_sum: i32 = board
+        .iter()
+        .filter(|(_, marked)| !marked)
+        .map(|(n, _)| *n)
+        .sum();
+    let score = lane_sum * draw;
+
+    Some(score)
+}
+
+/// No board completes a row or column with the given draws.
+#[derive(Clone, Copy, Debug)]
+struct NoWinner;
+
+impl Display for NoWinner {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "No board wins with the given draws")
+    }
+}
+
+impl Error for NoWinner {}
+
+#[anyhoo::anyhoo]
+fn main() {
+    let (draws, boards) = parse_input()?;
+
+    report::try_measure_and_print(1, || {
+        let mut boards = boards.clone();
+
+        draws
+            .iter()
+            .find_map(|&d| boards.iter_mut().find_map(|b| mark_board(b, d)))
+            .ok_or(NoWinner)
+    })?;
+
+    report::try_measure_and_print(2, || {
+        let mut boards_marked = boards.iter().cloned().map(|b| (b, false)).collect_vec();
+
+        draws
+            .iter()
+            .flat_map(|&d| {
+                boards_marked
+                    .iter_mut()
+                    .filter(|(_, marked)| !*marked)
+                    .filter_map(|(b, marked)| {
+                        let score = mark_board(b, d);
+
+                        if score.is_some() {
+                            *marked = true;
+                        }
+
+                        score
+                    })
+                    .collect_vec()
+            })
+            .last()
+            .ok_or(NoWinner)
+    })?;
+}
@@ -0,0 +1,107 @@
+use std::collections::HashMap;
+use std::iter::repeat;
+
+use aoc::input::Input;
+use aoc::parse::{self, ParseError};
+use aoc::report;
+use itertools::Itertools;
+
+#[anyhoo::anyhoo]
+fn parse_input() -> Vec<Line> {
+    let input = Input::parse_args(5, "Day 5: Hydrothermal Venture")?;
+
+    let lines = input.read_lines()?;
+
+    parse::lines(&lines).map(parse_line).try_collect()?
+}
+
+fn parse_line(line: parse::Line) -> Result<Line, ParseError> {
+    let mut cursor = line.cursor();
+    let start = parse_point(&mut cursor)?;
+    cursor.tag(" -> ")?;
+    let end = parse_point(&mut cursor)?;
+    cursor.end()?;
+
+    Ok(Line { start, end })
+}
+
+fn parse_point(cursor: &mut parse::Cursor) -> Result<(i32, i32), ParseError> {
+    let x = cursor.integer()?;
+    cursor.tag(",")?;
+    let y = cursor.integer()?;
+
+    Ok((x, y))
+}
+
+type DynIter<T> = Box<dyn Iterator<Item = T>>;
+
+struct Line {
+    start: (i32, i32),
+    end: (i32, i32),
+}
+
+impl Line {
+    fn is_straight(&self) -> bool {
+        let (x1, y1) = self.start;
+        let (x2, y2) = self.end;
+
+        x1 == x2 || y1 == y2
+    }
+
+    fn points(&self) -> DynIter<(i32, i32)> {
+        let (x1, y1) = self.start;
+        let (x2, y2) = self.end;
+
+        if x1 == x2 {
+            let start_y = std::cmp::min(y1, y2);
+            let end_y = std::cmp::max(y1, y2);
+            let it = repeat(x1).zip(start_y..=end_y);
+
+            return Box::new(it);
+        }
+
+        if y1 == y2 {
+            let start_x = std::cmp::min(x1, x2);
+            let end_x = std::cmp::max(x1, x2);
+            let it = (start_x..=end_x).zip(repeat(y1));
+
+            return Box::new(it);
+        }
+
+        let it_x: DynIter<i32> = if x1 < x2 {
+            Box::new(x1..=x2)
+        } else {
+            Box::new((x2..=x1).rev())
+        };
+
+        let it_y: DynIter<i32> = if y1 < y2 {
+            Box::new(y1..=y2)
+        } else {
+            Box::new((y2..=y1).rev())
+        };
+
+        let it = (it_x).zip(it_y);
+
+        Box::new(it)
+    }
+}
+
+fn count_overlaps<'a>(lines: impl Iterator<Item = &'a Line>) -> usize {
+    let mut covers = HashMap::new();
+
+    for point in lines.flat_map(Line::points) {
+        *covers.entry(point).or_default() += 1;
+    }
+
+    covers.into_values().filter(|&n: &usize| n > 1).count()
+}
+
+#[anyhoo::anyhoo]
+fn main() {
+    let input = parse_input()?;
+
+    report::measure_and_print(1, || {
+        count_overlaps(input.iter().filter(|l| l.is_straight()))
+    });
+    report::measure_and_print(2, || count_overlaps(input.iter()));
+}
@@ -0,0 +1,137 @@
+use std::error::Error;
+use std::fmt::{self, Display};
+
+use aoc::input::Input;
+use aoc::params::{Param, Params};
+use aoc::parse::{self, ParseError};
+use aoc::report;
+use itertools::Itertools;
+
+#[anyhoo::anyhoo]
+fn parse_input() -> (Vec<usize>, Params) {
+    let (input, params) = Input::parse_args_with(
+        6,
+        "Day 6: Lanternfish",
+        &[
+            Param::new::<usize>("days1", 80, "Number of days to simulate in part 1"),
+            Param::new::<usize>("days2", 256, "Number of days to simulate in part 2"),
+        ],
+    )?;
+
+    let lines = input.read_lines()?;
+    let line = parse::first_line(&lines, "comma-separated timers")?;
+
+    let timers = line
+        .text()
+        .split(',')
+        .map(|token| parse_timer(line, token))
+        .try_collect()?;
+
+    (timers, params)
+}
+
+fn parse_timer(line: parse::Line, token: &str) -> Result<usize, ParseError> {
+    let timer = line.parse(token)?;
+
+    if timer >= N_PHASES {
+        return Err(line.error(token, format!("a timer below {}", N_PHASES)));
+    }
+
+    Ok(timer)
+}
+
+const N_PHASES: usize = 9;
+type Phases = [usize; N_PHASES];
+
+fn init_phases(initial: Vec<usize>) -> Phases {
+    let mut result = [0; N_PHASES];
+
+    for phase in initial {
+        result[phase] += 1;
+    }
+
+    result
+}
+
+/// The number of fish no longer fits a `usize` after this many days.
+#[derive(Clone, Copy, Debug)]
+struct TooManyFish(usize);
+
+/// Advances the phases by a day, returning `None` if the count of a phase overflows.
+fn step_day(mut phases: Phases) -> Option<Phases> {
+    // remember how many fish have spawned this cycle
+    let spawned = phases[0];
+
+    // shift each phase
+    for (curr, prev) in (0..N_PHASES).tuple_windows() {
+        phases[curr] = phases[prev];
+    }
+
+    // calculate spawned fish and fish on new cycle
+    phases[N_PHASES - 1] = spawned;
+    phases[6] = phases[6].checked_add(spawned)?;
+
+    Some(phases)
+}
+
+fn count_fish(mut phases: Phases, days: usize) -> Result<usize, TooManyFish> {
+    for day in 1..=days {
+        phases = step_day(phases).ok_or(TooManyFish(day))?;
+    }
+
+    phases
+        .into_iter()
+        .try_fold(0usize, usize::checked_add)
+        .ok_or(TooManyFish(days))
+}
+
+impl Display for TooManyFish {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "The number of lanternfish overflows after {} days",
+            self.0
+        )
+    }
+}
+
+impl Error for TooManyFish {}
+
+#[anyhoo::anyhoo]
+fn main() {
+    let (input, params) = parse_input()?;
+    let phases = init_phases(input);
+
+    report::try_measure_and_print(1, || count_fish(phases, params.get("days1")))?;
+    report::try_measure_and_print(2, || count_fish(phases, params.get("days2")))?;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_timers(text: &str) -> Result<Vec<usize>, ParseError> {
+        let line = parse::Line::new(1, text);
+
+        line.text()
+            .split(',')
+            .map(|t| parse_timer(line, t))
+            .collect()
+    }
+
+    #[test]
+    fn example() {
+        let phases = init_phases(parse_timers("3,4,3,1,2").unwrap());
+
+        assert_eq!(count_fish(phases, 18).unwrap(), 26);
+        assert_eq!(count_fish(phases, 80).unwrap(), 5934);
+        assert_eq!(count_fish(phases, 256).unwrap(), 26984457539);
+    }
+
+    #[test]
+    fn rejects_timers_above_8() {
+        let error = parse_timers("3,4,9,1").unwrap_err();
+
+        assert_eq!((error.column, error.found.as_str()), (5, "9"));
+    }
+}
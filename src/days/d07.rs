@@ -0,0 +1,41 @@
+use aoc::input::Input;
+use aoc::parse;
+use aoc::report;
+use itertools::Itertools;
+
+#[anyhoo::anyhoo]
+fn parse_input() -> Vec<i32> {
+    let input = Input::parse_args(7, "Day 7: The Treachery of Whales")?;
+
+    let lines = input.read_lines()?;
+    let line = parse::first_line(&lines, "comma-separated positions")?;
+
+    line.parse_list(",")?
+}
+
+fn gauss(v: i32) -> i32 {
+    (v * (v + 1)) / 2
+}
+
+fn fuel_cost(p: i32, x: i32) -> i32 {
+    let d = (p - x).abs();
+
+    gauss(d)
+}
+
+fn min_total_fuel(crabs: &[i32], fuel: impl Fn(i32, i32) -> i32) -> i32 {
+    let (min, max) = crabs.iter().copied().minmax().into_option().unwrap();
+
+    (min..=max)
+        .map(|p| crabs.iter().map(|&crab| fuel(p, crab)).sum())
+        .min()
+        .unwrap()
+}
+
+#[anyhoo::anyhoo]
+fn main() {
+    let input = parse_input()?;
+
+    report::measure_and_print(1, || min_total_fuel(&input, |p, crab| (crab - p).abs()));
+    report::measure_and_print(2, || min_total_fuel(&input, fuel_cost));
+}
@@ -0,0 +1,211 @@
+use std::collections::BTreeSet;
+use std::error::Error;
+use std::fmt::{self, Display};
+
+use aoc::input::Input;
+use aoc::parse::{self, ParseError};
+use aoc::report;
+use itertools::Itertools;
+
+#[anyhoo::anyhoo]
+fn parse_input() -> Vec<Entry> {
+    let input = Input::parse_args(8, "Day 8: Seven Segment Search")?;
+
+    let lines = input.read_lines()?;
+
+    parse::lines(&lines).map(parse_entry).try_collect()?
+}
+
+fn parse_entry(line: parse::Line) -> Result<Entry, ParseError> {
+    let mut cursor = line.cursor();
+    let pats = cursor.until(" | ")?;
+    let outs = cursor.rest();
+
+    let (pats, outs) = (parse_digits(line, pats)?, parse_digits(line, outs)?);
+
+    let format = "`<10 patterns> | <4 digits>`";
+    let pats: [_; 10] = pats.try_into().map_err(|_| line.mismatch(format))?;
+    let outs: [_; 4] = outs.try_into().map_err(|_| line.mismatch(format))?;
+
+    // every digit lights a different number of segments, apart from 2, 3, 5 and 0, 6, 9
+    let lengths = pats
+        .iter()
+        .map(|(_, segs)| segs.len())
+        .sorted()
+        .collect_vec();
+    if lengths != [2, 3, 4, 5, 5, 5, 6, 6, 6, 7] || !pats.iter().map(|(_, segs)| segs).all_unique()
+    {
+        return Err(line.mismatch("one pattern for each of the 10 digits"));
+    }
+
+    for (token, out) in &outs {
+        if !pats.iter().any(|(_, pat)| pat == out) {
+            return Err(line.error(token, "a digit among the patterns"));
+        }
+    }
+
+    Ok(Entry {
+        line: line.number(),
+        pats: pats.map(|(_, segs)| segs),
+        outs: outs.map(|(_, segs)| segs),
+    })
+}
+
+/// Parses space-separated digits, keeping their tokens for error reporting.
+fn parse_digits<'a>(line: parse::Line, text: &'a str) -> Result<Vec<(&'a str, Segs)>, ParseError> {
+    text.split(' ')
+        .map(|token| parse_segments(line, token))
+        .collect()
+}
+
+/// Parses the segments lit by a digit, each of `a` to `g` at most once.
+fn parse_segments<'a>(line: parse::Line, token: &'a str) -> Result<(&'a str, Segs), ParseError> {
+    if token.is_empty() {
+        return Err(line.error(token, "segments `a` to `g`"));
+    }
+
+    if let Some((i, c)) = token
+        .char_indices()
+        .find(|&(_, c)| !('a'..='g').contains(&c))
+    {
+        return Err(line.error(&token[i..i + c.len_utf8()], "segments `a` to `g`"));
+    }
+
+    let segs: Segs = token.chars().collect();
+
+    if segs.len() != token.len() {
+        return Err(line.error(token, "segments lit at most once"));
+    }
+
+    Ok((token, segs))
+}
+
+/// Segments lit in a digit, in a set that can itself be hashed.
+type Segs = BTreeSet<char>;
+
+struct Entry {
+    /// Where the entry was read from, for reporting miswired displays.
+    line: usize,
+    pats: [Segs; 10],
+    outs: [Segs; 4],
+}
+
+impl Entry {
+    // segment numeration:
+    //  _  <- 0
+    // | | <- 1 2
+    //  _  <- 3
+    // | | <- 4 5
+    //  _  <- 6
+    /// Reads the output value, or `None` if the patterns are not wired like a display.
+    fn decode(&self) -> Option<u32> {
+        let one = self.pats.iter().find(|s| s.len() == 2)?;
+        let four = self.pats.iter().find(|s| s.len() == 4)?;
+        let segs13: Segs = four.difference(one).copied().collect();
+
+        let digits = self.pats.iter().map(|pat| match pat.len() {
+            2 => 1,
+            3 => 7,
+            4 => 4,
+            7 => 8,
+            5 if pat.is_superset(one) => 3,
+            5 if pat.is_superset(&segs13) => 5,
+            5 => 2,
+            6 if pat.is_superset(four) => 9,
+            6 if pat.is_superset(&segs13) => 6,
+            _ => 0,
+        });
+        let digits = digits.collect_vec();
+
+        if !digits.iter().all_unique() {
+            return None;
+        }
+
+        self.outs.iter().try_fold(0, |acc, out| {
+            let i = self.pats.iter().position(|pat| pat == out)?;
+
+            Some(10 * acc + digits[i])
+        })
+    }
+}
+
+/// The patterns of an entry can't be matched up with the digits.
+#[derive(Clone, Copy, Debug)]
+struct Miswired(usize);
+
+impl Display for Miswired {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "The patterns on line {} don't match the digits of any wiring",
+            self.0
+        )
+    }
+}
+
+impl Error for Miswired {}
+
+#[anyhoo::anyhoo]
+fn main() {
+    let input = parse_input()?;
+
+    report::measure_and_print(1, || {
+        input
+            .iter()
+            .flat_map(|entry| &entry.outs)
+            .filter(|out| [2, 3, 4, 7].contains(&out.len()))
+            .count()
+    });
+
+    report::try_measure_and_print(2, || {
+        input
+            .iter()
+            .map(|entry| entry.decode().ok_or(Miswired(entry.line)))
+            .sum::<Result<u32, _>>()
+    })?;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str =
+        "acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab | cdfeb fcadb cdfeb cdbaf";
+
+    fn parse(text: &str) -> Result<Entry, ParseError> {
+        parse_entry(parse::Line::new(1, text))
+    }
+
+    #[test]
+    fn example() {
+        assert_eq!(parse(EXAMPLE).unwrap().decode(), Some(5353));
+    }
+
+    #[test]
+    fn rejects_malformed_entries() {
+        let column = |text: &str| parse(text).err().map(|e| e.column);
+
+        // a segment outside a-g, and too few patterns
+        assert_eq!(column("ab abc | ab abc ab z"), Some(20));
+        assert_eq!(column("ab abc | ab abc ab ag"), Some(1));
+        // ten patterns, but two of them have three segments
+        assert_eq!(
+            column("acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eaf cagedb ab | ab ab ab ab"),
+            Some(1)
+        );
+        // a repeated segment
+        assert_eq!(column(&EXAMPLE.replace("eafb", "eafe")), Some(45));
+        // an output that none of the patterns show
+        assert_eq!(column(&EXAMPLE.replace("| cdfeb", "| cdfea")), Some(62));
+        assert_eq!(column(&EXAMPLE.replace(" cdbaf", "")), Some(1));
+    }
+
+    #[test]
+    fn reports_miswired_patterns() {
+        // two patterns of five segments both contain the segments of 1, like 3 does
+        let entry =
+            parse("ab abd abef abcde abcdf acdfg abcdef bcdefg abcdeg abcdefg | ab ab ab ab");
+
+        assert_eq!(entry.unwrap().decode(), None);
+    }
+}
@@ -0,0 +1,53 @@
+use std::cmp::Reverse;
+
+use aoc::grid::{self, Connectivity, Grid, Pos};
+use aoc::input::Input;
+use aoc::regions;
+use aoc::report;
+use itertools::Itertools;
+
+#[anyhoo::anyhoo]
+fn parse_input() -> Floor {
+    let input = Input::parse_args(9, "Day 9: Smoke Basin")?;
+
+    let lines = input.read_lines()?;
+
+    grid::parse_digits(&lines)?
+}
+
+type Floor = Grid<u32>;
+
+fn check_lowpoint(pos: Pos, floor: &Floor) -> Option<u32> {
+    let v = floor[pos];
+
+    floor.neighbors4(pos).all(|p| floor[p] > v).then(|| v + 1)
+}
+
+fn search_basins(floor: &Floor) -> Vec<usize> {
+    let basins = regions::label(floor, Connectivity::Four, |&height| height != 9);
+
+    basins.regions.into_iter().map(|r| r.size).collect()
+}
+
+#[anyhoo::anyhoo]
+fn main() {
+    let floor = parse_input()?;
+
+    report::measure_and_print(1, || {
+        floor
+            .positions()
+            .filter_map(|p| check_lowpoint(p, &floor))
+            .sum::<u32>()
+    });
+
+    report::measure_and_print(2, || {
+        let basins = search_basins(&floor);
+
+        basins
+            .into_iter()
+            .map(Reverse)
+            .k_smallest(3)
+            .map(|Reverse(x)| x)
+            .product::<usize>()
+    });
+}
@@ -0,0 +1,187 @@
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::ops::ControlFlow;
+
+use aoc::input::Input;
+use aoc::parse::{self, ParseError};
+use aoc::report;
+use itertools::Itertools;
+
+#[anyhoo::anyhoo]
+fn parse_input() -> Vec<String> {
+    let input = Input::parse_args(10, "Day 10: Syntax Scoring")?;
+
+    let lines = input.read_lines()?;
+
+    parse::lines(&lines).map(parse_chunks).try_collect()?
+}
+
+fn parse_chunks(line: parse::Line) -> Result<String, ParseError> {
+    let text = line.text();
+
+    match text.char_indices().find(|&(_, c)| !"()[]{}<>".contains(c)) {
+        Some((i, c)) => Err(line.error(&text[i..i + c.len_utf8()], "a bracket")),
+        None => Ok(text.to_string()),
+    }
+}
+
+fn check_corrupted(line: &str) -> Option<u64> {
+    let result = line.chars().try_fold(vec![], |mut stack, c| {
+        match (c, stack.last()) {
+            ('(' | '[' | '{' | '<', _) => {
+                stack.push(c);
+            }
+            (')', Some(&'(')) | (']', Some(&'[')) | ('}', Some(&'{')) | ('>', Some(&'<')) => {
+                stack.pop();
+            }
+            (')', _) => {
+                return ControlFlow::Break(3);
+            }
+            (']', _) => {
+                return ControlFlow::Break(57);
+            }
+            ('}', _) => {
+                return ControlFlow::Break(1197);
+            }
+            ('>', _) => {
+                return ControlFlow::Break(25137);
+            }
+            _ => unreachable!(),
+        };
+
+        ControlFlow::Continue(stack)
+    });
+
+    match result {
+        ControlFlow::Break(score) => Some(score),
+        _ => None,
+    }
+}
+
+/// Returns the chunks a line leaves open, innermost last, unless it is corrupted or complete.
+fn check_incomplete(line: &str) -> Option<Vec<char>> {
+    let result = line.chars().try_fold(vec![], |mut stack, c| {
+        match (c, stack.last()) {
+            ('(' | '[' | '{' | '<', _) => {
+                stack.push(c);
+            }
+            (')', Some(&'(')) | (']', Some(&'[')) | ('}', Some(&'{')) | ('>', Some(&'<')) => {
+                stack.pop();
+            }
+            (')' | ']' | '}' | '>', _) => {
+                return None;
+            }
+            _ => unreachable!(),
+        };
+
+        Some(stack)
+    });
+
+    // complete lines need no completion
+    result.filter(|open| !open.is_empty())
+}
+
+/// Scores the completion closing `open`, or `None` if the score overflows.
+fn completion_score(open: &[char]) -> Option<u64> {
+    open.iter().rev().try_fold(0u64, |score, c| {
+        let v = match c {
+            '(' => 1,
+            '[' => 2,
+            '{' => 3,
+            '<' => 4,
+            _ => unreachable!(),
+        };
+
+        score.checked_mul(5)?.checked_add(v)
+    })
+}
+
+/// Why there is no middle completion score.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Unscorable {
+    NoIncompleteLines,
+    /// The completion score of this line does not fit a u64.
+    Overflow(usize),
+}
+
+impl Display for Unscorable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoIncompleteLines => write!(f, "No line is incomplete"),
+            Self::Overflow(line) => write!(f, "The completion score of line {} overflows", line),
+        }
+    }
+}
+
+impl Error for Unscorable {}
+
+fn middle_score(lines: &[String]) -> Result<u64, Unscorable> {
+    let scores: Vec<_> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, l)| {
+            check_incomplete(l)
+                .map(|open| completion_score(&open).ok_or(Unscorable::Overflow(i + 1)))
+        })
+        .try_collect()?;
+
+    let scores = scores.into_iter().sorted_unstable().collect_vec();
+
+    scores
+        .get(scores.len() / 2)
+        .copied()
+        .ok_or(Unscorable::NoIncompleteLines)
+}
+
+#[anyhoo::anyhoo]
+fn main() {
+    let input = parse_input()?;
+
+    report::measure_and_print(1, || {
+        input.iter().filter_map(|l| check_corrupted(l)).sum::<u64>()
+    });
+
+    report::try_measure_and_print(2, || middle_score(&input))?;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let lines = [
+            "[({(<(())[]>[[{[]{<()<>>",
+            "[(()[<>])]({[<{<<[]>>(",
+            "{([(<{}[<>[]}>{[]{[(<()>",
+            "(((({<>}<{<{<>}{[]{[]{}",
+            "[[<[([]))<([[{}[[()]]]",
+            "[{[{({}]{}}([{[{{{}}([]",
+            "{<[[]]>}<{[{[{[]{()[[[]",
+            "[<(<(<(<{}))><([]([]()",
+            "<{([([[(<>()){}]>(<<{{",
+            "<{([{{}}[<[[[<>{}]]]>[]]",
+        ]
+        .map(String::from);
+
+        let corrupted: u64 = lines.iter().filter_map(|l| check_corrupted(l)).sum();
+
+        assert_eq!(corrupted, 26397);
+        assert_eq!(middle_score(&lines), Ok(288957));
+    }
+
+    #[test]
+    fn rejects_overflowing_scores() {
+        let lines = ["<".repeat(27), "<".repeat(28)];
+
+        assert!(completion_score(&['<'; 27]).is_some());
+        assert_eq!(middle_score(&lines), Err(Unscorable::Overflow(2)));
+    }
+
+    #[test]
+    fn needs_an_incomplete_line() {
+        let lines = ["()".to_string(), "(]".to_string()];
+
+        assert_eq!(middle_score(&lines), Err(Unscorable::NoIncompleteLines));
+    }
+}
@@ -0,0 +1,154 @@
+use std::error::Error;
+use std::fmt::{self, Display};
+
+use aoc::grid::{self, Connectivity, Grid};
+use aoc::input::Input;
+use aoc::params::{Param, Params};
+use aoc::report;
+
+#[anyhoo::anyhoo]
+fn parse_input() -> (Cavern, Params) {
+    let (input, params) = Input::parse_args_with(
+        11,
+        "Day 11: Dumbo Octopus",
+        &[
+            Param::new::<usize>("steps", 100, "Number of steps to simulate in part 1"),
+            Param::option::<usize>(
+                "flashes",
+                "N",
+                "Wait for N simultaneous flashes in part 2 instead of every octopus flashing",
+            )
+            .at_least(1),
+        ],
+    )?;
+
+    let lines = input.read_lines()?;
+
+    (grid::parse_digits(&lines)?, params)
+}
+
+type Cavern = Grid<u32>;
+
+/// The octopuses fell into a cycle without ever flashing this many at once.
+#[derive(Debug)]
+struct NeverFlash(usize);
+
+fn step_flash(cavern: &mut Cavern) -> usize {
+    let mut flashed = vec![];
+
+    for (pos, energy) in cavern.iter_mut() {
+        *energy += 1;
+
+        if *energy > 9 {
+            flashed.push(pos);
+        }
+    }
+
+    // every octopus flashes once, upon its energy reaching 10
+    cavern.flood_fill(flashed.clone(), Connectivity::Eight, |pos, energy| {
+        *energy += 1;
+
+        let flashes = *energy == 10;
+        if flashes {
+            flashed.push(pos);
+        }
+
+        flashes
+    });
+
+    for &pos in &flashed {
+        cavern[pos] = 0;
+    }
+
+    flashed.len()
+}
+
+/// Finds the first step during which `flashes` octopuses flash at once.
+fn first_step_flashing(cavern: &Cavern, flashes: usize) -> Result<usize, NeverFlash> {
+    let mut cavern = cavern.clone();
+    // Brent's cycle detection, keeping only the state after the latest power
+    // of two steps rather than every one
+    let mut saved = cavern.clone();
+
+    for step in 1.. {
+        if step_flash(&mut cavern) == flashes {
+            return Ok(step);
+        }
+        if cavern == saved {
+            break;
+        }
+        if usize::is_power_of_two(step) {
+            saved = cavern.clone();
+        }
+    }
+
+    Err(NeverFlash(flashes))
+}
+
+impl Display for NeverFlash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} octopuses never flash at once", self.0)
+    }
+}
+
+impl Error for NeverFlash {}
+
+#[anyhoo::anyhoo]
+fn main() {
+    let (cavern, params) = parse_input()?;
+    let steps: usize = params.get("steps");
+    let flashes = params
+        .get_option("flashes")
+        .unwrap_or(cavern.width() * cavern.height());
+
+    report::measure_and_print(1, || {
+        let mut cavern = cavern.clone();
+
+        std::iter::repeat_with(|| step_flash(&mut cavern))
+            .take(steps)
+            .sum::<usize>()
+    });
+
+    report::try_measure_and_print(2, || first_step_flashing(&cavern, flashes))?;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_cavern(lines: &[&str]) -> Cavern {
+        grid::parse_digits(lines).unwrap()
+    }
+
+    #[test]
+    fn example() {
+        let cavern = parse_cavern(&[
+            "5483143223",
+            "2745854711",
+            "5264556173",
+            "6141336146",
+            "6357385478",
+            "4167524645",
+            "2176841721",
+            "6882881134",
+            "4846848554",
+            "5283751526",
+        ]);
+
+        let mut stepped = cavern.clone();
+        let flashes: usize = (0..100).map(|_| step_flash(&mut stepped)).sum();
+
+        assert_eq!(flashes, 1656);
+        assert_eq!(first_step_flashing(&cavern, 100).unwrap(), 195);
+    }
+
+    #[test]
+    fn unreached_flash_counts_end_in_a_cycle() {
+        let cavern = parse_cavern(&["11111", "19991", "19191", "19991", "11111"]);
+
+        assert!(matches!(
+            first_step_flashing(&cavern, 3),
+            Err(NeverFlash(3))
+        ));
+    }
+}
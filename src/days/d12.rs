@@ -1,18 +1,22 @@
 use std::collections::{HashMap, HashSet};
 
-use aoc_utils::BufferedInput;
+use aoc::input::Input;
+use aoc::parse::{self, ParseError};
+use aoc::report;
 use itertools::Itertools;
-use scan_fmt::scan_fmt;
 
 #[anyhoo::anyhoo]
 fn parse_input() -> Tunnels {
-    let input = BufferedInput::parse_args("Day 12: Passage Pathing - Part 2")?;
+    let input = Input::parse_args(12, "Day 12: Passage Pathing")?;
 
-    input
-        .unwrapped_lines()
-        .flat_map(|l| {
-            let (a, b) = scan_fmt!(&l, "{}-{}", String, String).unwrap();
+    let lines = input.read_lines()?;
+    let edges: Vec<_> = parse::lines(&lines).map(parse_edge).try_collect()?;
 
+    check_start(&edges, lines.len())?;
+
+    edges
+        .into_iter()
+        .flat_map(|(a, b)| {
             if a == "start" {
                 return vec![(a, b)];
             }
@@ -26,14 +30,36 @@ fn parse_input() -> Tunnels {
         .into_group_map()
 }
 
+fn parse_edge(line: parse::Line) -> Result<(String, String), ParseError> {
+    let mut cursor = line.cursor();
+    let a = cursor.word()?;
+    cursor.tag("-")?;
+    let b = cursor.word()?;
+    cursor.end()?;
+
+    Ok((a.to_string(), b.to_string()))
+}
+
+/// Fails unless some tunnel leads out of the `start` cave.
+fn check_start(edges: &[(String, String)], n_lines: usize) -> Result<(), ParseError> {
+    match edges.iter().any(|(a, b)| a == "start" || b == "start") {
+        true => Ok(()),
+        false => Err(ParseError::end_of_input(n_lines + 1, "a tunnel from start")),
+    }
+}
+
 type Tunnels = HashMap<String, Vec<String>>;
 
 fn is_small(cave: &str) -> bool {
     cave.chars().all(|c| c.is_ascii_lowercase())
 }
 
-fn sweep_paths(tunnels: &Tunnels) -> usize {
-    let mut searchspace: Vec<(&str, HashSet<&str>, bool)> = vec![("start", [].into(), false)];
+/// Counts the paths from `start` to `end` visiting small caves at most once,
+/// except for a single small cave visited twice when `double_visit` is allowed.
+fn sweep_paths(tunnels: &Tunnels, double_visit: bool) -> usize {
+    // the double visit counts as already used when it is not allowed
+    let start = ("start", HashSet::new(), !double_visit);
+    let mut searchspace: Vec<(&str, HashSet<&str>, bool)> = vec![start];
     let mut result = 0;
 
     while let Some((curr, small_visits, double_visit)) = searchspace.pop() {
@@ -69,5 +95,6 @@ fn sweep_paths(tunnels: &Tunnels) -> usize {
 fn main() {
     let input = parse_input()?;
 
-    aoc_utils::measure_and_print(|| sweep_paths(&input));
+    report::measure_and_print(1, || sweep_paths(&input, false));
+    report::measure_and_print(2, || sweep_paths(&input, true));
 }
@@ -0,0 +1,227 @@
+use std::collections::HashSet;
+use std::error::Error;
+use std::fmt::{self, Display};
+
+use aoc::grid::Grid;
+use aoc::input::Input;
+use aoc::ocr::{self, OcrError};
+use aoc::params::{Param, Params};
+use aoc::parse::{self, ParseError};
+use aoc::report;
+use itertools::{chain, Itertools};
+
+#[anyhoo::anyhoo]
+fn parse_input() -> (Foil, Vec<Fold>, Params) {
+    let (input, params) = Input::parse_args_with(
+        13,
+        "Day 13: Transparent Origami",
+        &[Param::switch(
+            "render",
+            "Print the paper folded in part 2 instead of reading its letters",
+        )],
+    )?;
+
+    let lines = input.read_lines()?;
+    let mut sections = parse::sections(&lines);
+
+    let points = sections
+        .next()
+        .ok_or_else(|| ParseError::end_of_input(lines.len() + 1, "dot coordinates"))?;
+    let points = points.into_iter().map(parse_point).try_collect()?;
+
+    let folds = sections
+        .next()
+        .ok_or_else(|| ParseError::end_of_input(lines.len() + 1, "fold instructions"))?;
+    let folds = folds.into_iter().map(parse_fold).try_collect()?;
+
+    (points, folds, params)
+}
+
+fn parse_point(line: parse::Line) -> Result<Point, ParseError> {
+    let mut cursor = line.cursor();
+    let x = cursor.integer()?;
+    cursor.tag(",")?;
+    let y = cursor.integer()?;
+    cursor.end()?;
+
+    Ok((x, y))
+}
+
+fn parse_fold(line: parse::Line) -> Result<Fold, ParseError> {
+    let mut cursor = line.cursor();
+    cursor.tag("fold along ")?;
+    let axis = cursor.word()?;
+    cursor.tag("=")?;
+    let val = cursor.integer()?;
+    cursor.end()?;
+
+    match axis {
+        "x" => Ok(Fold::Vertical(val)),
+        "y" => Ok(Fold::Horizontal(val)),
+        _ => Err(line.error(axis, "x or y")),
+    }
+}
+
+type Point = (i32, i32);
+type Foil = HashSet<Point>;
+
+#[derive(Clone, Copy, Debug)]
+enum Fold {
+    Horizontal(i32),
+    Vertical(i32),
+}
+
+fn make_fold(foil: Foil, fold: Fold) -> Foil {
+    match fold {
+        Fold::Horizontal(y) => fold_horizontal(foil, y),
+        Fold::Vertical(x) => fold_vertical(foil, x),
+    }
+}
+
+fn fold_vertical(foil: Foil, fold_x: i32) -> Foil {
+    let (folded, original): (Vec<_>, Vec<_>) = foil.iter().partition(|(x, _)| x > &fold_x);
+
+    let folded = folded.into_iter().map(|(x, y)| {
+        let dist = x - fold_x;
+        let new_x = fold_x - dist;
+
+        (new_x, y)
+    });
+
+    chain!(original, folded).collect()
+}
+
+fn fold_horizontal(foil: Foil, fold_y: i32) -> Foil {
+    let (folded, original): (Vec<_>, Vec<_>) = foil.iter().partition(|(_, y)| y > &fold_y);
+
+    let folded = folded.into_iter().map(|(x, y)| {
+        let dist = y - fold_y;
+        let new_y = fold_y - dist;
+
+        (x, new_y)
+    });
+
+    chain!(original, folded).collect()
+}
+
+/// Draws the dots from the origin, where the letters' cells start.
+fn foil_pixels(foil: &Foil) -> Result<Grid<bool>, Unreadable> {
+    // folding off-centre can leave dots left of or above the origin
+    if let Some(&dot) = foil.iter().find(|&&(x, y)| x < 0 || y < 0) {
+        return Err(Unreadable::OffPaper(dot));
+    }
+
+    let (width, height) = foil.iter().fold((0, 0), |(w, h), &(x, y)| {
+        (std::cmp::max(w, x + 1), std::cmp::max(h, y + 1))
+    });
+
+    let mut pixels = Grid::from_elem(width as usize, height as usize, false);
+
+    for &(x, y) in foil {
+        pixels[(x as usize, y as usize)] = true;
+    }
+
+    Ok(pixels)
+}
+
+/// Why the folded paper could not be read.
+#[derive(Debug)]
+enum Unreadable {
+    OffPaper(Point),
+    Ocr(OcrError),
+}
+
+impl From<OcrError> for Unreadable {
+    fn from(error: OcrError) -> Self {
+        Self::Ocr(error)
+    }
+}
+
+impl Display for Unreadable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::OffPaper((x, y)) => write!(
+                f,
+                "The folds move the dot at {},{} off the paper's top left corner",
+                x, y
+            ),
+            Self::Ocr(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl Error for Unreadable {}
+
+#[anyhoo::anyhoo]
+fn main() {
+    let (foil, folds, params) = parse_input()?;
+    let render: bool = params.get("render");
+
+    report::measure_and_print(1, || make_fold(foil.clone(), folds[0]).len());
+
+    report::try_measure_and_print(2, || -> Result<_, Unreadable> {
+        let folded = folds.iter().copied().fold(foil.clone(), make_fold);
+        let pixels = foil_pixels(&folded)?;
+
+        if render {
+            Ok(ocr::render(&pixels))
+        } else {
+            Ok(ocr::recognize(&pixels)?)
+        }
+    })?;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_letters_from_the_origin() {
+        // `I` has a blank first column, so the dots don't start at x=0
+        let lines = ["fold along x=12", "fold along y=7"];
+        let folds = parse::lines(&lines)
+            .map(parse_fold)
+            .try_collect::<_, Vec<_>, _>()
+            .unwrap();
+        let foil: Foil = [
+            (1, 0),
+            (2, 0),
+            (3, 0),
+            (2, 1),
+            (2, 2),
+            (2, 3),
+            (2, 4),
+            (1, 5),
+            (2, 5),
+            (3, 5),
+            (5, 0),
+            (5, 1),
+            (5, 2),
+            (5, 3),
+            (5, 4),
+            (5, 5),
+            (6, 5),
+            (7, 5),
+            (8, 5),
+        ]
+        .into_iter()
+        .map(|(x, y)| (24 - x, 14 - y))
+        .collect();
+
+        let folded = folds.into_iter().fold(foil, make_fold);
+        let pixels = foil_pixels(&folded).unwrap();
+
+        assert_eq!(ocr::recognize(&pixels), Ok("IL".to_string()));
+    }
+
+    #[test]
+    fn rejects_dots_folded_off_the_paper() {
+        let foil: Foil = [(0, 0), (9, 0)].into_iter().collect();
+        let folded = make_fold(foil, Fold::Vertical(2));
+
+        assert!(matches!(
+            foil_pixels(&folded),
+            Err(Unreadable::OffPaper((-5, 0)))
+        ));
+    }
+}
@@ -0,0 +1,122 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::{self, Display};
+
+use aoc::input::Input;
+use aoc::params::{Param, Params};
+use aoc::parse::{self, ParseError};
+use aoc::report;
+use itertools::Itertools;
+
+#[anyhoo::anyhoo]
+fn parse_input() -> (String, PairInserts, Params) {
+    let (input, params) = Input::parse_args_with(
+        14,
+        "Day 14: Extended Polymerization",
+        &[
+            Param::new::<usize>("steps1", 10, "Number of pair insertion steps in part 1"),
+            Param::new::<usize>("steps2", 40, "Number of pair insertion steps in part 2"),
+        ],
+    )?;
+
+    let lines = input.read_lines()?;
+
+    let template = parse::first_line(&lines, "a polymer template")?;
+    let mut cursor = template.cursor();
+    cursor.word()?;
+    cursor.end()?;
+
+    let inserts = parse::sections(&lines)
+        .nth(1)
+        .ok_or_else(|| ParseError::end_of_input(lines.len() + 1, "pair insertion rules"))?;
+    let inserts = inserts.into_iter().map(parse_insert).try_collect()?;
+
+    (template.text().to_string(), inserts, params)
+}
+
+fn parse_insert(line: parse::Line) -> Result<((char, char), char), ParseError> {
+    let mut cursor = line.cursor();
+    let pair = cursor.word()?;
+    cursor.tag(" -> ")?;
+    let insert = cursor.word()?;
+    cursor.end()?;
+
+    let pair = pair
+        .chars()
+        .collect_tuple()
+        .ok_or_else(|| line.error(pair, "a pair of elements"))?;
+    let insert = insert
+        .chars()
+        .exactly_one()
+        .map_err(|_| line.error(insert, "a single element"))?;
+
+    Ok((pair, insert))
+}
+
+type Pairs = HashMap<(char, char), usize>;
+type Counts = HashMap<char, usize>;
+type PairInserts = HashMap<(char, char), char>;
+
+fn init_auxiliary(sequence: &str) -> (Pairs, Counts) {
+    let pairs = sequence.chars().tuple_windows().counts();
+    let counts = sequence.chars().counts();
+
+    (pairs, counts)
+}
+
+/// The element counts no longer fit a `usize` after this many steps.
+#[derive(Clone, Copy, Debug)]
+struct TooLong(usize);
+
+/// Applies the insertions once, returning `None` if a count overflows.
+fn step_polymerize(pairs: Pairs, counts: &mut Counts, inserts: &PairInserts) -> Option<Pairs> {
+    let mut result = Pairs::new();
+
+    for (pair @ (a, b), count) in pairs {
+        let produced = match inserts.get(&pair) {
+            Some(&el) => {
+                let total = counts.entry(el).or_default();
+                *total = total.checked_add(count)?;
+
+                vec![(a, el), (el, b)]
+            }
+            None => vec![pair],
+        };
+
+        for pair in produced {
+            let total = result.entry(pair).or_default();
+            *total = total.checked_add(count)?;
+        }
+    }
+
+    Some(result)
+}
+
+fn polymerize(template: &str, inserts: &PairInserts, steps: usize) -> Result<usize, TooLong> {
+    let (mut pairs, mut counts) = init_auxiliary(template);
+
+    for step in 1..=steps {
+        pairs = step_polymerize(pairs, &mut counts, inserts).ok_or(TooLong(step))?;
+    }
+
+    // the template is never empty
+    let (min, max) = counts.into_values().minmax().into_option().unwrap();
+
+    Ok(max - min)
+}
+
+impl Display for TooLong {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "The element counts overflow after {} steps", self.0)
+    }
+}
+
+impl Error for TooLong {}
+
+#[anyhoo::anyhoo]
+fn main() {
+    let (template, inserts, params) = parse_input()?;
+
+    report::try_measure_and_print(1, || polymerize(&template, &inserts, params.get("steps1")))?;
+    report::try_measure_and_print(2, || polymerize(&template, &inserts, params.get("steps2")))?;
+}
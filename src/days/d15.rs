@@ -0,0 +1,84 @@
+use aoc::grid::{self, Grid};
+use aoc::input::Input;
+use aoc::params::{Param, Params};
+use aoc::report;
+use aoc::search::{self, GridCosts};
+use itertools::Itertools;
+use ndarray::Array2;
+
+#[anyhoo::anyhoo]
+fn parse_input() -> (Map, Params) {
+    let (input, params) = Input::parse_args_with(
+        15,
+        "Day 15: Chiton",
+        &[Param::new::<usize>(
+            "tiles",
+            5,
+            "Number of times the map is repeated in each direction in part 2",
+        )
+        .at_least(1)],
+    )?;
+
+    let lines = input.read_lines()?;
+
+    (grid::parse_digits(&lines)?, params)
+}
+
+type Map = Grid<u32>;
+
+fn init_map(tile: &Map, tiles: usize) -> Map {
+    let (width, height) = (tile.width(), tile.height());
+
+    let mut small_chunk = tile.as_array().clone();
+
+    // allocate a `tiles` times bigger 2D array
+    let mut full = Array2::<u32>::zeros((tiles * height, tiles * width));
+
+    // copy tiles into place, applying the "+1 with wrap" transformation
+    for chunk_row in &full
+        .exact_chunks_mut((height, width))
+        .into_iter()
+        .chunks(tiles)
+    {
+        let mut x = small_chunk.clone();
+
+        for mut view in chunk_row {
+            view.assign(&x);
+
+            x.map_inplace(|el| *el = if *el < 9 { *el + 1 } else { 1 });
+        }
+
+        small_chunk.map_inplace(|el| *el = if *el < 9 { *el + 1 } else { 1 });
+    }
+
+    Grid::from(full)
+}
+
+fn sweep_risk(map: &Map) -> u32 {
+    let end = (map.width() - 1, map.height() - 1);
+
+    let costs = GridCosts::new(map.width(), map.height());
+    let path = search::dijkstra_in(
+        costs,
+        [(0, 0)],
+        |&pos| map.neighbors4(pos).map(|n| (n, map[n])),
+        |&pos| pos == end,
+    );
+
+    path.expect("The bottom right corner is always reachable")
+        .cost
+}
+
+#[anyhoo::anyhoo]
+fn main() {
+    let (tile, params) = parse_input()?;
+    let tiles: usize = params.get("tiles");
+
+    report::measure_and_print(1, || sweep_risk(&tile));
+
+    report::measure_and_print(2, || {
+        let map = init_map(&tile, tiles);
+
+        sweep_risk(&map)
+    });
+}
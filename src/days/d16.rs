@@ -0,0 +1,39 @@
+use aoc::bits::{hexes_to_bits, Decoder, Lexer, Token};
+use aoc::input::Input;
+use aoc::parse;
+use aoc::report;
+use itertools::Itertools;
+
+#[anyhoo::anyhoo]
+fn parse_input() -> Vec<u8> {
+    let input = Input::parse_args(16, "Day 16: Packet Decoder")?;
+
+    let lines = input.read_lines()?;
+    let line = parse::first_line(&lines, "a hexadecimal transmission")?;
+
+    line.digits(16)?.into_iter().map(|hex| hex as u8).collect()
+}
+
+#[anyhoo::anyhoo]
+fn main() {
+    let input = parse_input()?;
+
+    report::try_measure_and_print(1, || {
+        let bits = hexes_to_bits(input.iter().copied());
+        let lexer = Lexer::from_bits(bits);
+
+        lexer
+            .filter_map_ok(|token| match token {
+                Token::Version(ver) => Some(ver as u64),
+                _ => None,
+            })
+            .sum::<Result<u64, _>>()
+    })?;
+
+    report::try_measure_and_print(2, || {
+        let bits = hexes_to_bits(input.iter().copied());
+        let decoder = Decoder::from_bits(bits);
+
+        decoder.decode()
+    })?;
+}
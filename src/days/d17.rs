@@ -0,0 +1,270 @@
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+use aoc::input::Input;
+use aoc::params::{Param, Params};
+use aoc::parse::{self, ParseError};
+use aoc::report;
+use itertools::iproduct;
+
+/// Largest trajectory drawn by `--trace`, in cells.
+const MAX_PLOT_WIDTH: i32 = 150;
+const MAX_PLOT_HEIGHT: i32 = 80;
+
+#[anyhoo::anyhoo]
+fn parse_input() -> (Target, Params) {
+    let (input, params) = Input::parse_args_with(
+        17,
+        "Day 17: Trick Shot",
+        &[Param::option::<Velocity>(
+            "trace",
+            "VX,VY",
+            "Print the trajectory of a probe launched with the given velocity",
+        )],
+    )?;
+
+    let lines = input.read_lines()?;
+    let line = parse::first_line(&lines, "a target area")?;
+
+    (parse_target(line)?, params)
+}
+
+fn parse_target(line: parse::Line) -> Result<Target, ParseError> {
+    let mut cursor = line.cursor();
+    cursor.tag("target area: x=")?;
+    let xs = cursor.ascending_range()?;
+    cursor.tag(", y=")?;
+    let ys = cursor.ascending_range()?;
+    cursor.end()?;
+
+    Ok(Target { xs, ys })
+}
+
+#[derive(Debug)]
+struct Target {
+    xs: RangeInclusive<i32>,
+    ys: RangeInclusive<i32>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+struct Velocity {
+    x: i32,
+    y: i32,
+}
+
+/// The flight of a probe, up to the step it hits the target or is bound to miss it.
+struct Flight {
+    positions: Vec<(i32, i32)>,
+    hit: bool,
+}
+
+impl FromStr for Velocity {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parsed = s
+            .split_once(',')
+            .and_then(|(x, y)| Some((x.trim().parse().ok()?, y.trim().parse().ok()?)));
+
+        match parsed {
+            Some((x, y)) => Ok(Self { x, y }),
+            None => Err(format!("Expected a velocity like `6,9`, found {:?}", s)),
+        }
+    }
+}
+
+impl Target {
+    fn contains(&self, (x, y): (i32, i32)) -> bool {
+        self.xs.contains(&x) && self.ys.contains(&y)
+    }
+
+    /// Initial velocities worth trying: any faster one overshoots the target in a single step.
+    fn velocities(&self) -> impl Iterator<Item = Velocity> {
+        let (&x1, &x2) = (self.xs.start(), self.xs.end());
+        let (&y1, &y2) = (self.ys.start(), self.ys.end());
+
+        // a probe thrown up comes back down through y=0 with its initial speed plus one
+        let vys = std::cmp::min(y1, 0)..=std::cmp::max(y1.abs(), y2.abs());
+        let vxs = std::cmp::min(x1, 0)..=std::cmp::max(x2, 0);
+
+        iproduct!(vxs, vys).map(|(x, y)| Velocity { x, y })
+    }
+
+    fn launch(&self, velocity: Velocity) -> Flight {
+        let (mut x, mut y) = (0, 0);
+        let Velocity {
+            x: mut vx,
+            y: mut vy,
+        } = velocity;
+        let mut positions = vec![(x, y)];
+
+        loop {
+            x += vx;
+            y += vy;
+            vx -= vx.signum();
+            vy -= 1;
+            positions.push((x, y));
+
+            if self.contains((x, y)) {
+                return Flight {
+                    positions,
+                    hit: true,
+                };
+            }
+
+            let falling_below = vy < 0 && y < *self.ys.start();
+            let stalled_beside = vx == 0 && !self.xs.contains(&x);
+
+            if falling_below || stalled_beside {
+                return Flight {
+                    positions,
+                    hit: false,
+                };
+            }
+        }
+    }
+}
+
+impl Flight {
+    fn apex(&self) -> i32 {
+        self.positions.iter().map(|&(_, y)| y).max().unwrap_or(0)
+    }
+}
+
+fn trace(target: &Target, velocity: Velocity) {
+    let flight = target.launch(velocity);
+
+    eprintln!(
+        "Probe launched with velocity {},{}:",
+        velocity.x, velocity.y
+    );
+
+    for (step, &pos) in flight.positions.iter().enumerate().skip(1) {
+        let marker = if target.contains(pos) {
+            "  <- in the target area"
+        } else {
+            ""
+        };
+
+        eprintln!("  step {:>4}: ({}, {}){}", step, pos.0, pos.1, marker);
+    }
+
+    if flight.hit {
+        eprintln!(
+            "Hits the target after {} steps, reaching a height of {}",
+            flight.positions.len() - 1,
+            flight.apex()
+        );
+    } else {
+        eprintln!("Misses the target");
+    }
+
+    if let Some(plot) = plot(target, &flight) {
+        eprintln!("{}", plot);
+    }
+}
+
+/// Draws the flight like the puzzle does: `S` for the start, `#` for the probe
+/// and `T` for the target area. Gives up on trajectories too large to read.
+fn plot(target: &Target, flight: &Flight) -> Option<String> {
+    let corners = [
+        (*target.xs.start(), *target.ys.start()),
+        (*target.xs.end(), *target.ys.end()),
+    ];
+    let points = flight.positions.iter().chain(&corners);
+
+    let min_x = points.clone().map(|p| p.0).min()?;
+    let max_x = points.clone().map(|p| p.0).max()?;
+    let min_y = points.clone().map(|p| p.1).min()?;
+    let max_y = points.map(|p| p.1).max()?;
+
+    if max_x - min_x >= MAX_PLOT_WIDTH || max_y - min_y >= MAX_PLOT_HEIGHT {
+        return None;
+    }
+
+    let rows = (min_y..=max_y).rev().map(|y| {
+        (min_x..=max_x)
+            .map(|x| match (x, y) {
+                (0, 0) => 'S',
+                pos if flight.positions.contains(&pos) => '#',
+                pos if target.contains(pos) => 'T',
+                _ => '.',
+            })
+            .collect::<String>()
+    });
+
+    Some(rows.collect::<Vec<_>>().join("\n"))
+}
+
+#[anyhoo::anyhoo]
+fn main() {
+    let (target, params) = parse_input()?;
+
+    report::measure_and_print(1, || {
+        target
+            .velocities()
+            .map(|v| target.launch(v))
+            .filter(|flight| flight.hit)
+            .map(|flight| flight.apex())
+            .max()
+            .expect("No velocity hits the target")
+    });
+
+    report::measure_and_print(2, || {
+        target
+            .velocities()
+            .filter(|&v| target.launch(v).hit)
+            .count()
+    });
+
+    if let Some(velocity) = params.get_option("trace") {
+        trace(&target, velocity);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn target(text: &str) -> Result<Target, ParseError> {
+        parse_target(parse::lines(&[text]).next().unwrap())
+    }
+
+    /// The highest apex and the number of velocities hitting the target.
+    fn hits(text: &str) -> (Option<i32>, usize) {
+        let target = target(text).unwrap();
+        let hits: Vec<_> = target
+            .velocities()
+            .map(|v| target.launch(v))
+            .filter(|flight| flight.hit)
+            .collect();
+
+        (hits.iter().map(Flight::apex).max(), hits.len())
+    }
+
+    #[test]
+    fn example() {
+        assert_eq!(hits("target area: x=20..30, y=-10..-5"), (Some(45), 112));
+    }
+
+    #[test]
+    fn mirrored_example() {
+        assert_eq!(hits("target area: x=-30..-20, y=-10..-5"), (Some(45), 112));
+    }
+
+    #[test]
+    fn rejects_descending_ranges() {
+        assert_eq!(
+            target("target area: x=30..20, y=-10..-5")
+                .unwrap_err()
+                .column,
+            16
+        );
+        assert_eq!(
+            target("target area: x=20..30, y=-5..-10")
+                .unwrap_err()
+                .column,
+            26
+        );
+    }
+}
@@ -0,0 +1,93 @@
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::num::NonZeroUsize;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+
+use aoc::input::Input;
+use aoc::parse;
+use aoc::report;
+use aoc::snailfish::Number;
+use itertools::Itertools;
+
+#[anyhoo::anyhoo]
+fn parse_input() -> Vec<Number> {
+    let input = Input::parse_args(18, "Day 18: Snailfish")?;
+
+    let lines = input.read_lines()?;
+
+    parse::lines(&lines).map(Number::parse).try_collect()?
+}
+
+/// The homework holds fewer numbers than a part needs, which is this many.
+#[derive(Debug)]
+struct TooFewNumbers(usize);
+
+/// Adds up all the numbers in order, failing if there are none.
+fn sum_magnitude(numbers: &[Number]) -> Result<u64, TooFewNumbers> {
+    let sum = numbers
+        .iter()
+        .cloned()
+        .reduce(|sum, number| sum + number)
+        .ok_or(TooFewNumbers(1))?;
+
+    Ok(sum.magnitude())
+}
+
+/// Finds the largest magnitude of a sum of two different numbers, in either order.
+///
+/// Every worker thread takes the next left operand to try against all right ones.
+fn max_pairwise_magnitude(numbers: &[Number]) -> Option<u64> {
+    let workers = thread::available_parallelism().map_or(1, NonZeroUsize::get);
+    let next = AtomicUsize::new(0);
+
+    thread::scope(|s| {
+        let handles = (0..workers)
+            .map(|_| {
+                s.spawn(|| {
+                    let mut best = None;
+
+                    loop {
+                        let i = next.fetch_add(1, Ordering::Relaxed);
+                        let left = match numbers.get(i) {
+                            Some(left) => left,
+                            None => break best,
+                        };
+
+                        let magnitudes = numbers
+                            .iter()
+                            .enumerate()
+                            .filter(|&(j, _)| j != i)
+                            .map(|(_, right)| (left + right).magnitude());
+
+                        best = best.into_iter().chain(magnitudes).max();
+                    }
+                })
+            })
+            .collect_vec();
+
+        handles
+            .into_iter()
+            .filter_map(|handle| handle.join().unwrap())
+            .max()
+    })
+}
+
+impl Display for TooFewNumbers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            1 => write!(f, "Need at least 1 number"),
+            n => write!(f, "Need at least {} numbers", n),
+        }
+    }
+}
+
+impl Error for TooFewNumbers {}
+
+#[anyhoo::anyhoo]
+fn main() {
+    let input = parse_input()?;
+
+    report::try_measure_and_print(1, || sum_magnitude(&input))?;
+    report::try_measure_and_print(2, || max_pairwise_magnitude(&input).ok_or(TooFewNumbers(2)))?;
+}
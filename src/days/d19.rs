@@ -0,0 +1,230 @@
+use std::cell::OnceCell;
+use std::collections::VecDeque;
+use std::error::Error;
+use std::fmt::{self, Display};
+
+use aoc::geom3::{self, Fingerprint, Point, Transform};
+use aoc::input::Input;
+use aoc::params::{Param, Params};
+use aoc::parse::{self, ParseError};
+use aoc::report;
+use itertools::Itertools;
+use rustc_hash::FxHashSet;
+
+#[anyhoo::anyhoo]
+fn parse_input() -> (Vec<Scanner>, Params) {
+    let (input, params) = Input::parse_args_with(
+        19,
+        "Day 19: Beacon Scanner",
+        &[Param::new::<usize>(
+            "overlap",
+            12,
+            "Number of beacons two scanners must share to be aligned",
+        )
+        .at_least(1)],
+    )?;
+
+    let lines = input.read_lines()?;
+
+    let scanners = parse::sections(&lines)
+        .map(|section| parse_scanner(&section))
+        .try_collect()?;
+
+    (scanners, params)
+}
+
+fn parse_scanner(section: &[parse::Line]) -> Result<Scanner, ParseError> {
+    let mut header = section[0].cursor();
+    header.tag("--- scanner ")?;
+    header.integer::<usize>()?;
+    header.tag(" ---")?;
+    header.end()?;
+
+    let beacons: Vec<_> = section[1..]
+        .iter()
+        .map(|&line| parse_beacon(line))
+        .try_collect()?;
+    let fingerprint = Fingerprint::new(&beacons);
+
+    Ok(Scanner {
+        beacons,
+        fingerprint,
+    })
+}
+
+fn parse_beacon(line: parse::Line) -> Result<Point, ParseError> {
+    let mut cursor = line.cursor();
+    let coords = cursor.separated(",", |c| c.integer())?;
+    cursor.end()?;
+
+    match coords[..] {
+        [x, y, z] => Ok(Point::new(x, y, z)),
+        _ => Err(line.mismatch("three coordinates")),
+    }
+}
+
+/// Beacons relative to a scanner, in its own orientation.
+struct Scanner {
+    beacons: Vec<Point>,
+    fingerprint: Fingerprint,
+}
+
+/// Indices of the scanners that share too few beacons with the others to be placed.
+#[derive(Clone, Debug)]
+struct Unaligned(Vec<usize>);
+
+/// Finds how each scanner is placed relative to the first one, working outwards
+/// from the scanners already placed to any unplaced ones they overlap with.
+///
+/// Fails if some scanners don't overlap with any placed ones.
+fn locate_scanners(scanners: &[Scanner], overlap: usize) -> Result<Vec<Transform>, Unaligned> {
+    let mut located = vec![None; scanners.len()];
+    let mut queue = VecDeque::new();
+
+    if let Some(first) = located.first_mut() {
+        *first = Some(Transform::IDENTITY);
+        queue.push_back((0, Transform::IDENTITY));
+    }
+
+    while let Some((i, placed)) = queue.pop_front() {
+        let scanner = &scanners[i];
+
+        for (j, other) in scanners.iter().enumerate() {
+            if located[j].is_some() || !scanner.fingerprint.may_overlap(&other.fingerprint, overlap)
+            {
+                continue;
+            }
+
+            if let Some(relative) = geom3::align(&scanner.beacons, &other.beacons, overlap) {
+                let transform = placed.compose(&relative);
+
+                located[j] = Some(transform);
+                queue.push_back((j, transform));
+            }
+        }
+    }
+
+    let unaligned = located.iter().positions(Option::is_none).collect_vec();
+
+    match unaligned.is_empty() {
+        true => Ok(located.into_iter().flatten().collect()),
+        false => Err(Unaligned(unaligned)),
+    }
+}
+
+impl Display for Unaligned {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Failed to align scanners {} with scanner 0",
+            self.0.iter().join(", ")
+        )
+    }
+}
+
+impl Error for Unaligned {}
+
+#[anyhoo::anyhoo]
+fn main() {
+    let (scanners, params) = parse_input()?;
+    let overlap = params.get("overlap");
+
+    // aligned by whichever part runs first, and shared with the other one
+    let located = OnceCell::new();
+    let transforms = || {
+        located
+            .get_or_init(|| locate_scanners(&scanners, overlap))
+            .as_ref()
+            .map_err(Unaligned::clone)
+    };
+
+    report::try_measure_and_print(1, || {
+        let beacons = scanners
+            .iter()
+            .zip(transforms()?)
+            .flat_map(|(scanner, transform)| scanner.beacons.iter().map(|&b| transform.apply(b)))
+            .collect::<FxHashSet<_>>();
+
+        Ok::<_, Unaligned>(beacons.len())
+    })?;
+
+    report::try_measure_and_print(2, || {
+        let distance = transforms()?
+            .iter()
+            .tuple_combinations()
+            .map(|(a, b)| a.offset.manhattan(b.offset))
+            .max()
+            .unwrap_or(0);
+
+        Ok::<_, Unaligned>(distance)
+    })?;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scanner(beacons: Vec<Point>) -> Scanner {
+        let fingerprint = Fingerprint::new(&beacons);
+
+        Scanner {
+            beacons,
+            fingerprint,
+        }
+    }
+
+    /// The first scanner sees all beacons, the others see overlapping parts of them
+    /// from their own positions and orientations.
+    fn scanners() -> (Vec<Scanner>, Vec<Transform>) {
+        let beacons = (0..8)
+            .map(|i| Point::new(i * 37 % 101, i * i * 13 % 89, i * 7 - 20))
+            .collect_vec();
+        let placed = [
+            Transform::IDENTITY,
+            Transform {
+                rotation: geom3::Rotation::all().nth(5).unwrap(),
+                offset: Point::new(-300, 40, 1000),
+            },
+            Transform {
+                rotation: geom3::Rotation::all().nth(22).unwrap(),
+                offset: Point::new(80, -900, 5),
+            },
+        ];
+        let seen = |transform: &Transform, range: std::ops::Range<usize>| {
+            let inverse = geom3::Rotation::all()
+                .find(|r| r.compose(&transform.rotation) == geom3::Rotation::IDENTITY)
+                .unwrap();
+
+            beacons[range]
+                .iter()
+                .map(|&b| inverse.apply(b - transform.offset))
+                .collect_vec()
+        };
+
+        let scanners = vec![
+            scanner(seen(&placed[0], 0..5)),
+            scanner(seen(&placed[1], 1..8)),
+            scanner(seen(&placed[2], 5..8)),
+        ];
+
+        (scanners, placed.to_vec())
+    }
+
+    #[test]
+    fn locates_through_other_scanners() {
+        let (scanners, placed) = scanners();
+
+        // the last scanner only shares beacons with the second one
+        let located = locate_scanners(&scanners, 3).unwrap();
+
+        assert_eq!(located, placed);
+    }
+
+    #[test]
+    fn reports_unaligned_scanners() {
+        let (scanners, _) = scanners();
+        let Unaligned(unaligned) = locate_scanners(&scanners, 4).unwrap_err();
+
+        assert_eq!(unaligned, [2]);
+    }
+}
@@ -0,0 +1,226 @@
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::fs;
+use std::path::PathBuf;
+
+use aoc::grid::{self, Grid, Pos};
+use aoc::input::Input;
+use aoc::params::{Param, Params};
+use aoc::parse::{self, ParseError};
+use aoc::report;
+use itertools::{iproduct, Itertools};
+
+const ALGORITHM_LEN: usize = 512;
+const PIXEL: &str = "`#` or `.`";
+
+#[anyhoo::anyhoo]
+fn parse_input() -> (Vec<bool>, Image, Params) {
+    let (input, params) = Input::parse_args_with(
+        20,
+        "Day 20: Trench Map",
+        &[
+            Param::new::<usize>("steps1", 2, "Number of enhancement steps in part 1"),
+            Param::new::<usize>("steps2", 50, "Number of enhancement steps in part 2"),
+            Param::option::<PathBuf>(
+                "dump",
+                "DIR",
+                "Write the image and its background after every step to DIR/stepNN.txt",
+            ),
+        ],
+    )?;
+
+    let lines = input.read_lines()?;
+
+    let algorithm = parse_algorithm(parse::first_line(&lines, "an enhancement algorithm")?)?;
+    let image = parse::sections(&lines)
+        .nth(1)
+        .ok_or_else(|| ParseError::end_of_input(lines.len() + 1, "an image"))?;
+    let pixels = grid::parse_cells(image, PIXEL, pixel)?;
+
+    let image = Image {
+        pixels,
+        background: false,
+    };
+
+    (algorithm, image, params)
+}
+
+fn parse_algorithm(line: parse::Line) -> Result<Vec<bool>, ParseError> {
+    let text = line.text();
+    let algorithm: Vec<_> = text
+        .char_indices()
+        .map(|(i, c)| pixel(c).ok_or_else(|| line.error(&text[i..i + c.len_utf8()], PIXEL)))
+        .try_collect()?;
+
+    if algorithm.len() != ALGORITHM_LEN {
+        return Err(line.mismatch(format!("{} pixels", ALGORITHM_LEN)));
+    }
+
+    Ok(algorithm)
+}
+
+fn pixel(c: char) -> Option<bool> {
+    match c {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    }
+}
+
+/// A finite window onto an infinite image, all of whose pixels outside
+/// the window are equal to `background`.
+#[derive(Clone)]
+struct Image {
+    pixels: Grid<bool>,
+    background: bool,
+}
+
+/// The image has a lit background after the given number of steps.
+#[derive(Debug)]
+struct InfinitelyLit(usize);
+
+impl Image {
+    /// Applies the algorithm to every pixel, growing the window by one pixel on each side.
+    ///
+    /// The background enhances like any pixel surrounded by the background,
+    /// so it flips every step when the algorithm lights up a dark neighbourhood.
+    fn enhance(&self, algorithm: &[bool]) -> Self {
+        let (width, height) = (self.pixels.width() + 2, self.pixels.height() + 2);
+        let mut pixels = Grid::from_elem(width, height, false);
+
+        for (pos, pixel) in pixels.iter_mut() {
+            *pixel = algorithm[self.neighborhood(pos)];
+        }
+
+        let surrounded = if self.background {
+            ALGORITHM_LEN - 1
+        } else {
+            0
+        };
+        let background = algorithm[surrounded];
+
+        Self { pixels, background }
+    }
+
+    /// Reads the 3x3 pixels centered on `pos`, given in the grown window, as a binary number.
+    fn neighborhood(&self, (x, y): Pos) -> usize {
+        iproduct!(0..3, 0..3).fold(0, |index, (dy, dx)| {
+            // the grown window starts one pixel up and left of this one
+            let lit = (x + dx)
+                .checked_sub(2)
+                .zip((y + dy).checked_sub(2))
+                .and_then(|pos| self.pixels.get(pos).copied())
+                .unwrap_or(self.background);
+
+            index << 1 | usize::from(lit)
+        })
+    }
+
+    /// Counts the lit pixels, or returns `None` if infinitely many are lit.
+    fn lit(&self) -> Option<usize> {
+        (!self.background).then(|| self.pixels.iter().filter(|(_, &lit)| lit).count())
+    }
+}
+
+impl Display for Image {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let pixel = |lit: bool| if lit { '#' } else { '.' };
+        let pixels = self.pixels.map(|&lit| pixel(lit));
+
+        writeln!(f, "background: {}", pixel(self.background))?;
+        write!(f, "{}", pixels)
+    }
+}
+
+impl Display for InfinitelyLit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Infinitely many pixels are lit after {} steps", self.0)
+    }
+}
+
+impl Error for InfinitelyLit {}
+
+/// The image followed by every enhancement of it.
+fn enhanced<'a>(image: &Image, algorithm: &'a [bool]) -> impl Iterator<Item = Image> + 'a {
+    std::iter::successors(Some(image.clone()), move |image| {
+        Some(image.enhance(algorithm))
+    })
+}
+
+/// Enhances the image `steps` times and counts its lit pixels.
+fn count_lit(image: &Image, algorithm: &[bool], steps: usize) -> Result<usize, InfinitelyLit> {
+    let image = enhanced(image, algorithm).nth(steps).unwrap();
+
+    image.lit().ok_or(InfinitelyLit(steps))
+}
+
+#[anyhoo::anyhoo]
+fn main() {
+    let (algorithm, image, params) = parse_input()?;
+    let steps1: usize = params.get("steps1");
+    let steps2: usize = params.get("steps2");
+
+    // written first and outside the timed parts, so the images are there even if a part fails
+    if let Some(dir) = params.get_option::<PathBuf>("dump") {
+        fs::create_dir_all(&dir)?;
+
+        let steps = steps1.max(steps2);
+        for (step, image) in enhanced(&image, &algorithm).take(steps + 1).enumerate() {
+            fs::write(
+                dir.join(format!("step{:02}.txt", step)),
+                format!("{}\n", image),
+            )?;
+        }
+    }
+
+    report::try_measure_and_print(1, || count_lit(&image, &algorithm, steps1))?;
+    report::try_measure_and_print(2, || count_lit(&image, &algorithm, steps2))?;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_image(lines: &[&str]) -> Image {
+        let pixels = grid::parse_cells(parse::lines(lines), PIXEL, pixel).unwrap();
+
+        Image {
+            pixels,
+            background: false,
+        }
+    }
+
+    #[test]
+    fn example() {
+        let algorithm = concat!(
+            "..#.#..#####.#.#.#.###.##.....###.##.#..###.####..#####..#....#..#..##..##",
+            "#..######.###...####..#..#####..##..#.#####...##.#.#..#.##..#.#......#.###",
+            ".######.###.####...#.##.##..#..#..#####.....#.#....###..#.##......#.....#.",
+            ".#..#..##..#...##.######.####.####.#.#...#.......#..#.#.#...####.##.#.....",
+            ".#..#...##.#.##..#...##.#.##..###.#......#.#.......#.#.#.####.###.##...#..",
+            "...####.#..#..#.##.#....##..#.####....##...##..#...#......#.#.......#.....",
+            "..##..####..#...#.#.#...##..#.#..###..#####........#..####......#..#",
+        );
+        let algorithm = parse_algorithm(parse::lines(&[algorithm]).next().unwrap()).unwrap();
+        let image = parse_image(&["#..#.", "#....", "##..#", "..#..", "..###"]);
+
+        assert_eq!(count_lit(&image, &algorithm, 2).unwrap(), 35);
+        assert_eq!(count_lit(&image, &algorithm, 50).unwrap(), 3351);
+    }
+
+    #[test]
+    fn background_flips_when_dark_neighborhoods_light_up() {
+        // inverts the center pixel, so index 0 is lit and the last one dark
+        let algorithm: Vec<_> = (0..ALGORITHM_LEN).map(|i| i & 0b10000 == 0).collect();
+        let image = parse_image(&["#..", ".#.", "##."]);
+
+        let background = enhanced(&image, &algorithm).map(|image| image.background);
+        assert_eq!(background.take(4).collect_vec(), [false, true, false, true]);
+
+        assert_eq!(count_lit(&image, &algorithm, 0).unwrap(), 4);
+        assert!(count_lit(&image, &algorithm, 1).is_err());
+        assert_eq!(count_lit(&image, &algorithm, 2).unwrap(), 4);
+        assert!(count_lit(&image, &algorithm, 3).is_err());
+        assert_eq!(count_lit(&image, &algorithm, 4).unwrap(), 4);
+    }
+}
@@ -0,0 +1,287 @@
+use std::error::Error;
+use std::fmt::{self, Display};
+
+use aoc::input::Input;
+use aoc::params::{Param, Params};
+use aoc::parse::{self, ParseError};
+use aoc::report;
+use rustc_hash::FxHashMap;
+
+const DISTRIBUTION: [(u64, u128); 7] = [(3, 1), (4, 3), (5, 6), (6, 7), (7, 6), (8, 3), (9, 1)];
+
+#[anyhoo::anyhoo]
+fn parse_input() -> (u32, u32, Params) {
+    let (input, params) = Input::parse_args_with(
+        21,
+        "Day 21: Dirac Dice",
+        &[
+            Param::new::<u64>("score1", 1000, "Score needed to win in part 1"),
+            Param::new::<u64>("die", 100, "Number of sides of the deterministic die").at_least(1),
+            Param::new::<u64>("score2", 21, "Score needed to win in part 2"),
+        ],
+    )?;
+
+    let lines = input.read_lines()?;
+    let mut lines = parse::lines(&lines);
+
+    let pos_p1 = parse_start(lines.next(), 1)?;
+    let pos_p2 = parse_start(lines.next(), 2)?;
+
+    (pos_p1, pos_p2, params)
+}
+
+fn parse_start(line: Option<parse::Line>, player: usize) -> Result<u32, ParseError> {
+    let prefix = format!("Player {} starting position: ", player);
+
+    let line = line.ok_or_else(|| ParseError::end_of_input(player, format!("`{}`", prefix)))?;
+    let mut cursor = line.cursor();
+    cursor.tag(&prefix)?;
+    let (pos, token) = cursor.consumed(|c| c.integer())?;
+    cursor.end()?;
+
+    if !(1..=10).contains(&pos) {
+        return Err(line.error(token, "a position between 1 and 10"));
+    }
+
+    Ok(pos)
+}
+
+#[derive(Debug)]
+struct DiracDice<I> {
+    die: I,
+    player_one: (u64, u64),
+    player_two: (u64, u64),
+    roll_count: u64,
+    winning_score: u64,
+    game_over: bool,
+}
+
+#[derive(Debug)]
+struct DeterministicDie {
+    sides: u64,
+    current: u64,
+}
+
+impl DeterministicDie {
+    fn new(sides: u64) -> Self {
+        Self { sides, current: 0 }
+    }
+}
+
+impl Iterator for DeterministicDie {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let value = self.current + 1;
+
+        self.current = (self.current + 1) % self.sides;
+
+        Some(value)
+    }
+}
+
+impl<I: Iterator<Item = u64>> DiracDice<I> {
+    fn new<T>(p1_start: u64, p2_start: u64, die: T, winning_score: u64) -> Self
+    where
+        T: IntoIterator<IntoIter = I>,
+    {
+        Self {
+            die: die.into_iter(),
+            player_one: (p1_start - 1, 0),
+            player_two: (p2_start - 1, 0),
+            roll_count: 0,
+            winning_score,
+            game_over: false,
+        }
+    }
+
+    fn play_round(&mut self) {
+        if self.game_over {
+            return;
+        }
+
+        let die = self.die.by_ref();
+
+        let roll_p1: u64 = die.take(3).sum();
+        let (pos, score) = &mut self.player_one;
+
+        *pos = (*pos + roll_p1) % 10;
+        *score += *pos + 1;
+
+        self.roll_count += 3;
+
+        if *score >= self.winning_score {
+            self.game_over = true;
+            return;
+        }
+
+        let roll_p2: u64 = die.take(3).sum();
+        let (pos, score) = &mut self.player_two;
+
+        *pos = (*pos + roll_p2) % 10;
+        *score += *pos + 1;
+
+        self.roll_count += 3;
+
+        if *score >= self.winning_score {
+            self.game_over = true;
+        }
+    }
+
+    /// The losing score times the number of rolls, which may not fit a `u64`.
+    fn secret(&self) -> Option<u128> {
+        if !self.game_over {
+            return None;
+        }
+
+        let (p1, p2) = (self.player_one.1, self.player_two.1);
+        let losing = std::cmp::min(p1, p2);
+
+        Some(u128::from(losing) * u128::from(self.roll_count))
+    }
+}
+
+type Game = DiracDice<DeterministicDie>;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+enum Player {
+    One,
+    Two,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+struct GameState {
+    next_player: Player,
+    p1: (u64, u64),
+    p2: (u64, u64),
+}
+
+/// The number of universes no longer fits a `u128`.
+#[derive(Clone, Copy, Debug)]
+struct TooManyUniverses;
+
+impl Player {
+    fn advance(&mut self) {
+        *self = match self {
+            Self::One => Self::Two,
+            Self::Two => Self::One,
+        };
+    }
+}
+
+impl GameState {
+    fn make_move(&mut self, mov: u64, winning_score: u64) -> Option<Player> {
+        let (pos, score) = match self.next_player {
+            Player::One => &mut self.p1,
+            Player::Two => &mut self.p2,
+        };
+
+        *pos = (*pos + mov) % 10;
+        *score += *pos + 1;
+
+        let result = (*score >= winning_score).then_some(self.next_player);
+
+        self.next_player.advance();
+
+        result
+    }
+}
+
+/// Counts the universes each player wins in, returning the larger count.
+///
+/// Games are played a turn at a time, and universes that reach the same
+/// positions and scores are merged, so each state is only expanded once per turn.
+fn play_game(p1: u64, p2: u64, winning_score: u64) -> Result<u128, TooManyUniverses> {
+    let initial = GameState {
+        next_player: Player::One,
+        p1: (p1 - 1, 0),
+        p2: (p2 - 1, 0),
+    };
+
+    let mut universes = FxHashMap::from_iter([(initial, 1u128)]);
+    let mut p1_wins = 0u128;
+    let mut p2_wins = 0u128;
+
+    while !universes.is_empty() {
+        let mut next = FxHashMap::default();
+
+        for (state, count) in universes {
+            for (mov, split) in DISTRIBUTION {
+                let mut state = state;
+                let split_universes = count.checked_mul(split).ok_or(TooManyUniverses)?;
+
+                let total = match state.make_move(mov, winning_score) {
+                    Some(Player::One) => &mut p1_wins,
+                    Some(Player::Two) => &mut p2_wins,
+                    None => next.entry(state).or_default(),
+                };
+
+                *total = total.checked_add(split_universes).ok_or(TooManyUniverses)?;
+            }
+        }
+
+        universes = next;
+    }
+
+    Ok(std::cmp::max(p1_wins, p2_wins))
+}
+
+impl Display for TooManyUniverses {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "The number of universes overflows")
+    }
+}
+
+impl Error for TooManyUniverses {}
+
+#[anyhoo::anyhoo]
+fn main() {
+    let (p1, p2, params) = parse_input()?;
+
+    report::measure_and_print(1, || {
+        let die = DeterministicDie::new(params.get("die"));
+        let score1 = params.get("score1");
+        let mut game = Game::new(p1.into(), p2.into(), die, score1);
+
+        let mut game_iter = std::iter::from_fn(|| {
+            game.play_round();
+            game.secret()
+        });
+
+        loop {
+            if let Some(v) = game_iter.next() {
+                return v;
+            }
+        }
+    });
+
+    report::try_measure_and_print(2, || play_game(p1.into(), p2.into(), params.get("score2")))?;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn practice(winning_score: u64) -> u128 {
+        let mut game = Game::new(4, 8, DeterministicDie::new(100), winning_score);
+
+        loop {
+            game.play_round();
+
+            if let Some(secret) = game.secret() {
+                return secret;
+            }
+        }
+    }
+
+    #[test]
+    fn example() {
+        assert_eq!(practice(1000), 739785);
+        assert_eq!(play_game(4, 8, 21).unwrap(), 444356092776315);
+    }
+
+    #[test]
+    fn secret_of_a_long_game_fits() {
+        assert!(practice(100000) > u128::from(u32::MAX));
+    }
+}
@@ -0,0 +1,193 @@
+use aoc::geom3::{Cuboid, Point};
+use aoc::input::Input;
+use aoc::params::{Param, Params};
+use aoc::parse::{self, ParseError};
+use aoc::report;
+use itertools::Itertools;
+use rustc_hash::FxHashMap;
+
+#[anyhoo::anyhoo]
+fn parse_input() -> (Vec<Step>, Params) {
+    let (input, params) = Input::parse_args_with(
+        22,
+        "Day 22: Reactor Reboot",
+        &[Param::new::<i64>(
+            "bound",
+            50,
+            "Part 1 initialization region spans -bound..=bound on each axis",
+        )
+        .at_least(0)],
+    )?;
+
+    let lines = input.read_lines()?;
+
+    let steps = parse::lines(&lines).map(parse_step).try_collect()?;
+
+    (steps, params)
+}
+
+fn parse_step(line: parse::Line) -> Result<Step, ParseError> {
+    let mut cursor = line.cursor();
+    let state = cursor.word()?;
+    let state = match state {
+        "on" => true,
+        "off" => false,
+        _ => return Err(line.error(state, "on or off")),
+    };
+    cursor.tag(" x=")?;
+    let xs = cursor.ascending_range::<i32>()?;
+    cursor.tag(",y=")?;
+    let ys = cursor.ascending_range::<i32>()?;
+    cursor.tag(",z=")?;
+    let zs = cursor.ascending_range::<i32>()?;
+    cursor.end()?;
+
+    let [xs, ys, zs] = [xs, ys, zs].map(|r| (i64::from(*r.start()), i64::from(*r.end())));
+    let cuboid = Cuboid::new(Point::new(xs.0, ys.0, zs.0), Point::new(xs.1, ys.1, zs.1))
+        .expect("Ranges are ascending");
+
+    Ok(Step { state, cuboid })
+}
+
+#[derive(Clone, Debug)]
+struct Step {
+    /// Whether the step turns the cubes on or off.
+    state: bool,
+    cuboid: Cuboid,
+}
+
+/// Lit cubes as cuboids counted with signs, so that cubes in overlapping
+/// cuboids add up to exactly one lit cube (or none).
+type Reactor = FxHashMap<Cuboid, i64>;
+
+fn execute_step(reactor: &mut Reactor, state: bool, cuboid: Cuboid) {
+    // cancel out whatever the cuboid overlaps, then count it once if it turns on
+    let overlaps: Vec<_> = reactor
+        .iter()
+        .filter_map(|(other, &sign)| Some((other.intersection(&cuboid)?, sign)))
+        .collect();
+
+    for (overlap, sign) in overlaps {
+        *reactor.entry(overlap).or_default() -= sign;
+    }
+
+    if state {
+        *reactor.entry(cuboid).or_default() += 1;
+    }
+
+    reactor.retain(|_, &mut sign| sign != 0);
+}
+
+/// Counts the cubes lit by the steps, only looking inside `region` if given.
+fn lit_volume(steps: &[Step], region: Option<Cuboid>) -> u128 {
+    let mut reactor = Reactor::default();
+
+    for step in steps {
+        let cuboid = match region {
+            Some(region) => region.intersection(&step.cuboid),
+            None => Some(step.cuboid),
+        };
+
+        if let Some(cuboid) = cuboid {
+            execute_step(&mut reactor, step.state, cuboid);
+        }
+    }
+
+    // cuboids of 32-bit coordinates hold fewer than 2^99 cubes, so the signed sum fits
+    let lit: i128 = reactor
+        .into_iter()
+        .map(|(cuboid, sign)| i128::from(sign) * cuboid.volume() as i128)
+        .sum();
+
+    lit as u128
+}
+
+#[anyhoo::anyhoo]
+fn main() {
+    let (input, params) = parse_input()?;
+    let init_region = Cuboid::around_origin(params.get("bound"));
+
+    report::measure_and_print(1, || lit_volume(&input, Some(init_region)));
+    report::measure_and_print(2, || lit_volume(&input, None));
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use itertools::iproduct;
+
+    use super::*;
+
+    fn parse_steps(lines: &[&str]) -> Vec<Step> {
+        parse::lines(lines).map(parse_step).try_collect().unwrap()
+    }
+
+    #[test]
+    fn example() {
+        let steps = parse_steps(&[
+            "on x=10..12,y=10..12,z=10..12",
+            "on x=11..13,y=11..13,z=11..13",
+            "off x=9..11,y=9..11,z=9..11",
+            "on x=10..10,y=10..10,z=10..10",
+        ]);
+
+        assert_eq!(lit_volume(&steps, Some(Cuboid::around_origin(50))), 39);
+        assert_eq!(lit_volume(&steps, None), 39);
+    }
+
+    #[test]
+    fn overlaps_match_brute_force() {
+        let steps = parse_steps(&[
+            "on x=-3..4,y=0..5,z=-2..2",
+            "on x=0..6,y=-4..2,z=0..5",
+            "off x=-1..2,y=-1..3,z=-1..1",
+            "on x=1..1,y=0..0,z=0..0",
+            "off x=3..8,y=-5..5,z=4..4",
+            "on x=-3..4,y=0..5,z=-2..2",
+            "off x=-2..5,y=1..1,z=-3..6",
+        ]);
+
+        let mut lit = HashSet::new();
+        for step in &steps {
+            let (min, max) = (step.cuboid.min(), step.cuboid.max());
+
+            for cube in iproduct!(min.x..=max.x, min.y..=max.y, min.z..=max.z) {
+                if step.state {
+                    lit.insert(cube);
+                } else {
+                    lit.remove(&cube);
+                }
+            }
+        }
+
+        let inside = |&&(x, y, z): &&(i64, i64, i64)| [x, y, z].iter().all(|c| c.abs() <= 2);
+
+        assert_eq!(lit_volume(&steps, None), lit.len() as u128);
+        assert_eq!(
+            lit_volume(&steps, Some(Cuboid::around_origin(2))),
+            lit.iter().filter(inside).count() as u128
+        );
+    }
+
+    #[test]
+    fn counts_beyond_64_bits() {
+        let steps = parse_steps(&[
+            "on x=-2000000..2000000,y=-2000000..2000000,z=-2000000..2000000",
+            "off x=0..0,y=0..0,z=0..0",
+        ]);
+
+        assert_eq!(lit_volume(&steps, None), 4_000_001u128.pow(3) - 1);
+        assert_eq!(
+            lit_volume(&steps, Some(Cuboid::around_origin(50))),
+            101u128.pow(3) - 1
+        );
+    }
+
+    #[test]
+    fn rejects_descending_ranges() {
+        let error = parse_step(parse::lines(&["on x=3..1,y=0..0,z=0..0"]).next().unwrap());
+
+        assert_eq!(error.unwrap_err().column, 6);
+    }
+}
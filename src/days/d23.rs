@@ -0,0 +1,481 @@
+use std::error::Error;
+use std::fmt::{self, Display};
+
+use aoc::input::Input;
+use aoc::params::{Param, Params};
+use aoc::parse::{self, ParseError};
+use aoc::report;
+use aoc::search::{self, Path};
+
+const ROOMS: usize = 4;
+const HALLWAY_LEN: usize = 11;
+/// Deepest rooms that still fit the burrow into 128 bits at 3 bits per cell.
+const MAX_DEPTH: usize = 7;
+const CELL_BITS: usize = 3;
+const CELL_MASK: u128 = (1 << CELL_BITS) - 1;
+
+const ENERGY: [u32; ROOMS] = [1, 10, 100, 1000];
+const KINDS: [char; ROOMS] = ['A', 'B', 'C', 'D'];
+/// Rows hidden by the folded part of the diagram in part 2.
+const UNFOLDED: [[u8; ROOMS]; 2] = [[3, 2, 1, 0], [3, 1, 0, 2]];
+
+#[anyhoo::anyhoo]
+fn parse_input() -> (Vec<[u8; ROOMS]>, Params) {
+    let (input, params) = Input::parse_args_with(
+        23,
+        "Day 23: Amphipod",
+        &[Param::switch(
+            "moves",
+            "Print the cheapest sequence of moves to stderr",
+        )],
+    )?;
+
+    let lines = input.read_lines()?;
+
+    (parse_burrow(&lines)?, params)
+}
+
+fn parse_burrow<S: AsRef<str>>(lines: &[S]) -> Result<Vec<[u8; ROOMS]>, ParseError> {
+    let mut numbered = parse::lines(lines);
+    let mut next_line = |expected: &str| {
+        numbered
+            .next()
+            .ok_or_else(|| ParseError::end_of_input(lines.len() + 1, expected))
+    };
+
+    next_line("the burrow's top wall")?
+        .cursor()
+        .tag("#############")?;
+    next_line("the hallway")?.cursor().tag("#...........#")?;
+
+    let first = next_line("a row of rooms")?;
+    let mut rows = vec![(first, parse_row(first, "###", "##")?)];
+
+    loop {
+        let line = next_line("a row of rooms")?;
+
+        if line.text().trim_end() == "  #########" {
+            break;
+        }
+
+        if rows.len() + UNFOLDED.len() == MAX_DEPTH {
+            return Err(line.mismatch("the burrow's bottom wall"));
+        }
+
+        rows.push((line, parse_row(line, "  #", "")?));
+    }
+
+    // every room ends up filled with amphipods of a single kind, so the burrow is
+    // unbalanced exactly if some amphipod comes after all the others of its kind
+    let mut counts = [0; ROOMS];
+
+    for (line, row) in &rows {
+        for &(kind, token) in row {
+            counts[usize::from(kind)] += 1;
+
+            if counts[usize::from(kind)] > rows.len() {
+                return Err(line.error(
+                    token,
+                    format!("at most {} amphipods of each kind", rows.len()),
+                ));
+            }
+        }
+    }
+
+    Ok(rows
+        .into_iter()
+        .map(|(_, row)| row.map(|(kind, _)| kind))
+        .collect())
+}
+
+/// Parses a row of rooms, keeping the token of each amphipod for error reporting.
+fn parse_row<'a>(
+    line: parse::Line<'a>,
+    prefix: &str,
+    suffix: &str,
+) -> Result<[(u8, &'a str); ROOMS], ParseError> {
+    let mut cursor = line.cursor();
+    let mut row = [(0, ""); ROOMS];
+
+    cursor.tag(prefix)?;
+
+    for (i, (kind, amphipod)) in row.iter_mut().enumerate() {
+        if i > 0 {
+            cursor.tag("#")?;
+        }
+
+        let token = cursor.take_while("an amphipod", |c| KINDS.contains(&c))?;
+        *kind = match KINDS
+            .iter()
+            .position(|&k| token.len() == 1 && token.starts_with(k))
+        {
+            Some(kind) => kind as u8,
+            None => return Err(line.error(token, "a single amphipod")),
+        };
+        *amphipod = token;
+    }
+
+    cursor.tag("#")?;
+    cursor.tag(suffix)?;
+
+    if !cursor.rest().trim_end().is_empty() {
+        return Err(line.error(cursor.rest(), "end of line"));
+    }
+
+    Ok(row)
+}
+
+/// The whole burrow packed into one integer, one cell after another:
+/// the hallway from left to right, then each room from top to bottom.
+///
+/// Every cell is either 0 when empty, or the kind of amphipod in it plus 1.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+struct Burrow {
+    cells: u128,
+    depth: usize,
+}
+
+/// No sequence of moves organizes the amphipods.
+#[derive(Debug)]
+struct Stuck;
+
+/// Where an amphipod stands, as coordinates in the burrow diagram without its walls.
+type Spot = (usize, usize);
+
+fn room_cell(room: usize, slot: usize) -> usize {
+    HALLWAY_LEN + room * MAX_DEPTH + slot
+}
+
+/// The hallway position right outside a room, where amphipods may never stop.
+fn entrance(room: usize) -> usize {
+    2 + 2 * room
+}
+
+fn is_entrance(x: usize) -> bool {
+    (0..ROOMS).any(|room| entrance(room) == x)
+}
+
+fn spot(cell: usize) -> Spot {
+    match cell.checked_sub(HALLWAY_LEN) {
+        None => (cell, 0),
+        Some(i) => (entrance(i / MAX_DEPTH), i % MAX_DEPTH + 1),
+    }
+}
+
+impl Burrow {
+    fn new(rows: &[[u8; ROOMS]]) -> Self {
+        let mut burrow = Self {
+            cells: 0,
+            depth: rows.len(),
+        };
+
+        for (slot, row) in rows.iter().enumerate() {
+            for (room, &kind) in row.iter().enumerate() {
+                burrow.set(room_cell(room, slot), Some(kind));
+            }
+        }
+
+        burrow
+    }
+
+    fn get(&self, cell: usize) -> Option<u8> {
+        let value = (self.cells >> (cell * CELL_BITS)) & CELL_MASK;
+
+        (value as u8).checked_sub(1)
+    }
+
+    fn set(&mut self, cell: usize, kind: Option<u8>) {
+        let value = kind.map_or(0, |kind| u128::from(kind) + 1);
+        let shift = cell * CELL_BITS;
+
+        self.cells = (self.cells & !(CELL_MASK << shift)) | (value << shift);
+    }
+
+    fn room(&self, room: usize) -> impl Iterator<Item = Option<u8>> + '_ {
+        (0..self.depth).map(move |slot| self.get(room_cell(room, slot)))
+    }
+
+    /// Whether the room holds only amphipods that belong there, so none of them needs to leave.
+    fn is_settled(&self, room: usize) -> bool {
+        self.room(room)
+            .flatten()
+            .all(|kind| usize::from(kind) == room)
+    }
+
+    fn is_organized(&self) -> bool {
+        (0..ROOMS).all(|room| self.room(room).all(|kind| kind == Some(room as u8)))
+    }
+
+    /// Whether the hallway is empty between `from` and `to`, not counting `from` itself.
+    fn is_clear(&self, from: usize, to: usize) -> bool {
+        let mut path = if from < to {
+            from + 1..to + 1
+        } else {
+            to..from
+        };
+
+        path.all(|x| self.get(x).is_none())
+    }
+
+    fn moved(&self, from: usize, to: usize) -> (Self, u32) {
+        let kind = self.get(from).expect("Only amphipods can move");
+        let ((x1, y1), (x2, y2)) = (spot(from), spot(to));
+        let steps = x1.abs_diff(x2) + y1 + y2;
+
+        let mut next = *self;
+        next.set(from, None);
+        next.set(to, Some(kind));
+
+        (next, steps as u32 * ENERGY[usize::from(kind)])
+    }
+
+    /// All legal moves along with the energy they take.
+    ///
+    /// Amphipods move from their starting room into the hallway and from
+    /// the hallway into their own room; moving straight from room to room
+    /// costs the same as stopping in the hallway on the way.
+    fn moves(&self) -> Vec<(Self, u32)> {
+        let mut moves = vec![];
+
+        for x in 0..HALLWAY_LEN {
+            let kind = match self.get(x) {
+                Some(kind) => usize::from(kind),
+                None => continue,
+            };
+
+            if !self.is_settled(kind) || !self.is_clear(x, entrance(kind)) {
+                continue;
+            }
+
+            let free = self.room(kind).take_while(Option::is_none).count();
+
+            if let Some(slot) = free.checked_sub(1) {
+                moves.push(self.moved(x, room_cell(kind, slot)));
+            }
+        }
+
+        for room in (0..ROOMS).filter(|&room| !self.is_settled(room)) {
+            let slot = self.room(room).take_while(Option::is_none).count();
+            let from = room_cell(room, slot);
+            let door = entrance(room);
+
+            let is_free = |&x: &usize| self.get(x).is_none();
+            let left = (0..door).rev().take_while(is_free);
+            let right = (door + 1..HALLWAY_LEN).take_while(is_free);
+
+            for x in left.chain(right).filter(|&x| !is_entrance(x)) {
+                moves.push(self.moved(from, x));
+            }
+        }
+
+        moves
+    }
+
+    /// A lower bound on the energy left to spend, as if amphipods could walk through each other.
+    ///
+    /// Every amphipod that has to move walks to its own room's entrance, and those
+    /// entering a room take at least the top free slots, one step deeper each.
+    fn min_energy_left(&self) -> u32 {
+        let mut energy = 0;
+        let mut entering = [0; ROOMS];
+
+        for x in 0..HALLWAY_LEN {
+            if let Some(kind) = self.get(x).map(usize::from) {
+                energy += x.abs_diff(entrance(kind)) as u32 * ENERGY[kind];
+                entering[kind] += 1;
+            }
+        }
+
+        for room in 0..ROOMS {
+            let cells = self.room(room).collect::<Vec<_>>();
+
+            for (slot, kind) in cells.iter().enumerate() {
+                let kind = match kind {
+                    Some(kind) => usize::from(*kind),
+                    None => continue,
+                };
+                let blocks_stranger = cells[slot..].iter().any(|&other| other != Some(room as u8));
+
+                if !blocks_stranger {
+                    continue;
+                }
+
+                // leaving its own room, it has to step aside and back
+                let across = match entrance(room).abs_diff(entrance(kind)) {
+                    0 => 2,
+                    distance => distance,
+                };
+
+                energy += (slot + 1 + across) as u32 * ENERGY[kind];
+                entering[kind] += 1;
+            }
+        }
+
+        let entering_energy = entering
+            .iter()
+            .zip(ENERGY)
+            .map(|(&n, energy)| energy * n * (n + 1) / 2);
+
+        energy + entering_energy.sum::<u32>()
+    }
+
+    /// Describes the move leading from `self` to `next`.
+    fn describe_move(&self, next: &Self) -> String {
+        let cells = 0..HALLWAY_LEN + ROOMS * MAX_DEPTH;
+        let from = cells
+            .clone()
+            .find(|&c| self.get(c).is_some() && next.get(c).is_none());
+        let to = cells
+            .into_iter()
+            .find(|&c| self.get(c).is_none() && next.get(c).is_some());
+
+        let (from, to) = match (from, to) {
+            (Some(from), Some(to)) => (from, to),
+            _ => return "Nothing moves".to_string(),
+        };
+
+        let (_, energy) = self.moved(from, to);
+        let kind = KINDS[usize::from(self.get(from).unwrap())];
+
+        format!(
+            "{} moves from {} to {}, using {} energy",
+            kind,
+            describe_cell(from),
+            describe_cell(to),
+            energy
+        )
+    }
+}
+
+fn describe_cell(cell: usize) -> String {
+    match cell.checked_sub(HALLWAY_LEN) {
+        None => format!("the hallway at {}", cell + 1),
+        Some(i) => format!("room {}", KINDS[i / MAX_DEPTH]),
+    }
+}
+
+impl Display for Burrow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let show = |cell| self.get(cell).map_or('.', |kind| KINDS[usize::from(kind)]);
+
+        writeln!(f, "#############")?;
+        writeln!(f, "#{}#", (0..HALLWAY_LEN).map(show).collect::<String>())?;
+
+        for slot in 0..self.depth {
+            let (edge, end) = if slot == 0 {
+                ("###", "###")
+            } else {
+                ("  #", "#")
+            };
+            let row = (0..ROOMS).map(|room| show(room_cell(room, slot)).to_string());
+
+            writeln!(f, "{}{}{}", edge, row.collect::<Vec<_>>().join("#"), end)?;
+        }
+
+        write!(f, "  #########")
+    }
+}
+
+/// Inserts the rows hidden by the fold below the first row of the diagram.
+fn unfold(rows: &[[u8; ROOMS]]) -> Vec<[u8; ROOMS]> {
+    [&rows[..1], &UNFOLDED, &rows[1..]].concat()
+}
+
+fn organize(burrow: Burrow) -> Result<Path<Burrow, u32>, Stuck> {
+    search::astar(
+        [burrow],
+        Burrow::moves,
+        Burrow::min_energy_left,
+        Burrow::is_organized,
+    )
+    .ok_or(Stuck)
+}
+
+impl Display for Stuck {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "The amphipods cannot be organized")
+    }
+}
+
+impl Error for Stuck {}
+
+fn print_moves(part: u32, path: &Path<Burrow, u32>) {
+    eprintln!("Part {}, using {} energy:", part, path.cost);
+    eprintln!("{}", path.nodes[0]);
+
+    for (before, after) in path.nodes.iter().zip(&path.nodes[1..]) {
+        eprintln!();
+        eprintln!("{}:", before.describe_move(after));
+        eprintln!("{}", after);
+    }
+
+    eprintln!();
+}
+
+#[anyhoo::anyhoo]
+fn main() {
+    let (rows, params) = parse_input()?;
+    let mut paths = vec![];
+
+    report::try_measure_and_print(1, || {
+        let path = organize(Burrow::new(&rows))?;
+        let cost = path.cost;
+
+        paths.push((1, path));
+        Ok::<_, Stuck>(cost)
+    })?;
+
+    report::try_measure_and_print(2, || {
+        let path = organize(Burrow::new(&unfold(&rows)))?;
+        let cost = path.cost;
+
+        paths.push((2, path));
+        Ok::<_, Stuck>(cost)
+    })?;
+
+    if params.get("moves") {
+        for (part, path) in &paths {
+            print_moves(*part, path);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: [&str; 5] = [
+        "#############",
+        "#...........#",
+        "###B#C#B#D###",
+        "  #A#D#C#A#",
+        "  #########",
+    ];
+
+    fn energy(rows: &[[u8; ROOMS]]) -> Option<u32> {
+        organize(Burrow::new(rows)).ok().map(|path| path.cost)
+    }
+
+    #[test]
+    fn part1_example() {
+        let rows = parse_burrow(&EXAMPLE).unwrap();
+
+        assert_eq!(energy(&rows), Some(12521));
+    }
+
+    #[test]
+    fn part2_example() {
+        let rows = parse_burrow(&EXAMPLE).unwrap();
+
+        assert_eq!(energy(&unfold(&rows)), Some(44169));
+    }
+
+    #[test]
+    fn unbalanced_burrow() {
+        let mut lines = EXAMPLE;
+        lines[3] = "  #A#D#C#C#";
+
+        let error = parse_burrow(&lines).unwrap_err();
+        assert_eq!((error.line, error.column), (4, 10));
+    }
+}
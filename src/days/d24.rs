@@ -0,0 +1,610 @@
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+use aoc::input::Input;
+use aoc::params::{Param, Params};
+use aoc::parse::{self, ParseError};
+use aoc::report;
+use itertools::Itertools;
+use rustc_hash::FxHashSet;
+
+const REGISTERS: [&str; 4] = ["w", "x", "y", "z"];
+const Z: usize = 3;
+
+const LARGEST_FIRST: [i64; 9] = [9, 8, 7, 6, 5, 4, 3, 2, 1];
+const SMALLEST_FIRST: [i64; 9] = [1, 2, 3, 4, 5, 6, 7, 8, 9];
+
+#[anyhoo::anyhoo]
+fn parse_input() -> (Vec<Instr>, Params) {
+    let (input, params) = Input::parse_args_with(
+        24,
+        "Day 24: Arithmetic Logic Unit",
+        &[Param::option::<ModelNumber>(
+            "trace",
+            "MODEL",
+            "Print every step of the program checking the given model number",
+        )],
+    )?;
+
+    let lines = input.read_lines()?;
+
+    let program = parse::lines(&lines).map(parse_instr).try_collect()?;
+
+    (program, params)
+}
+
+fn parse_instr(line: parse::Line) -> Result<Instr, ParseError> {
+    let mut cursor = line.cursor();
+    let name = cursor.word()?;
+    cursor.tag(" ")?;
+    let dest = parse_register(&mut cursor)?;
+
+    let op = match name {
+        "inp" => {
+            cursor.end()?;
+            return Ok(Instr::Inp(dest));
+        }
+        "add" => Op::Add,
+        "mul" => Op::Mul,
+        "div" => Op::Div,
+        "mod" => Op::Mod,
+        "eql" => Op::Eql,
+        _ => return Err(line.error(name, "an instruction")),
+    };
+
+    cursor.tag(" ")?;
+    let operand = if cursor.rest().starts_with(char::is_alphabetic) {
+        Operand::Reg(parse_register(&mut cursor)?)
+    } else {
+        Operand::Num(cursor.integer()?)
+    };
+    cursor.end()?;
+
+    Ok(Instr::Op(op, dest, operand))
+}
+
+fn parse_register(cursor: &mut parse::Cursor) -> Result<usize, ParseError> {
+    let name = cursor.word()?;
+
+    REGISTERS
+        .iter()
+        .position(|&r| r == name)
+        .ok_or_else(|| cursor.line().error(name, "one of w, x, y or z"))
+}
+
+/// Values of the w, x, y and z registers.
+type Registers = [i64; 4];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Op {
+    Add,
+    Mul,
+    Div,
+    Mod,
+    Eql,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Operand {
+    Reg(usize),
+    Num(i64),
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Instr {
+    Inp(usize),
+    Op(Op, usize, Operand),
+}
+
+/// Why the ALU stopped before the end of the program.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Fault {
+    DivisionByZero,
+    InvalidModulo(i64, i64),
+    MissingInput,
+    /// The result of the instruction does not fit a register.
+    Overflow(Op, i64, i64),
+}
+
+/// No model number is accepted by the program.
+#[derive(Clone, Copy, Debug)]
+struct NoModelNumber;
+
+/// A model number given on the command line, one digit at a time.
+#[derive(Clone, Debug, PartialEq, PartialOrd)]
+struct ModelNumber(Vec<i64>);
+
+impl FromStr for ModelNumber {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.chars()
+            .map(|c| c.to_digit(10).map(i64::from))
+            .collect::<Option<_>>()
+            .map(Self)
+            .ok_or_else(|| format!("Expected only digits, found {:?}", s))
+    }
+}
+
+impl Op {
+    fn name(self) -> &'static str {
+        match self {
+            Op::Add => "add",
+            Op::Mul => "mul",
+            Op::Div => "div",
+            Op::Mod => "mod",
+            Op::Eql => "eql",
+        }
+    }
+}
+
+impl Instr {
+    fn execute<I>(self, regs: &mut Registers, input: &mut I) -> Result<(), Fault>
+    where
+        I: Iterator<Item = i64>,
+    {
+        let (op, dest, operand) = match self {
+            Instr::Inp(dest) => {
+                regs[dest] = input.next().ok_or(Fault::MissingInput)?;
+                return Ok(());
+            }
+            Instr::Op(op, dest, operand) => (op, dest, operand),
+        };
+
+        let (a, b) = match operand {
+            Operand::Reg(src) => (regs[dest], regs[src]),
+            Operand::Num(n) => (regs[dest], n),
+        };
+
+        let result = match op {
+            Op::Add => a.checked_add(b),
+            Op::Mul => a.checked_mul(b),
+            Op::Div if b == 0 => return Err(Fault::DivisionByZero),
+            // dividing the smallest value by -1 overflows too
+            Op::Div => a.checked_div(b),
+            Op::Mod if a < 0 || b <= 0 => return Err(Fault::InvalidModulo(a, b)),
+            Op::Mod => Some(a % b),
+            Op::Eql => Some(i64::from(a == b)),
+        };
+        regs[dest] = result.ok_or(Fault::Overflow(op, a, b))?;
+
+        Ok(())
+    }
+
+    /// Registers whose value the instruction depends on.
+    fn reads(self) -> impl Iterator<Item = usize> {
+        let (first, second) = match self {
+            Instr::Inp(_) => (None, None),
+            // multiplying by zero clears the register whatever it held
+            Instr::Op(Op::Mul, _, Operand::Num(0)) => (None, None),
+            Instr::Op(_, dest, Operand::Reg(src)) => (Some(dest), Some(src)),
+            Instr::Op(_, dest, Operand::Num(_)) => (Some(dest), None),
+        };
+
+        first.into_iter().chain(second)
+    }
+
+    fn writes(self) -> usize {
+        match self {
+            Instr::Inp(dest) | Instr::Op(_, dest, _) => dest,
+        }
+    }
+}
+
+impl Display for Instr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (name, dest, operand) = match *self {
+            Instr::Inp(dest) => return write!(f, "inp {}", REGISTERS[dest]),
+            Instr::Op(op, dest, operand) => (op.name(), dest, operand),
+        };
+
+        match operand {
+            Operand::Reg(src) => write!(f, "{} {} {}", name, REGISTERS[dest], REGISTERS[src]),
+            Operand::Num(n) => write!(f, "{} {} {}", name, REGISTERS[dest], n),
+        }
+    }
+}
+
+impl Display for Fault {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Fault::DivisionByZero => write!(f, "division by zero"),
+            Fault::InvalidModulo(a, b) => write!(f, "invalid modulo {} % {}", a, b),
+            Fault::MissingInput => write!(f, "ran out of input digits"),
+            Fault::Overflow(op, a, b) => write!(f, "overflow in {} {} {}", op.name(), a, b),
+        }
+    }
+}
+
+impl Display for NoModelNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "No model number is accepted")
+    }
+}
+
+impl Error for NoModelNumber {}
+
+fn run(program: &[Instr], regs: &mut Registers, input: &[i64]) -> Result<(), Fault> {
+    let mut input = input.iter().copied();
+
+    program
+        .iter()
+        .try_for_each(|instr| instr.execute(regs, &mut input))
+}
+
+/// Cuts the program before every `inp`, returning the instructions before the first one
+/// and the blocks starting with each `inp`.
+fn split_blocks(program: &[Instr]) -> (&[Instr], Vec<&[Instr]>) {
+    let starts = program
+        .iter()
+        .positions(|instr| matches!(instr, Instr::Inp(_)))
+        .collect_vec();
+    let ends = starts.iter().skip(1).copied().chain([program.len()]);
+
+    let prologue = &program[..starts.first().copied().unwrap_or(program.len())];
+    let blocks = starts
+        .iter()
+        .zip(ends)
+        .map(|(&start, end)| &program[start..end])
+        .collect();
+
+    (prologue, blocks)
+}
+
+/// The block repeated by every MONAD listing, where only `DIV`, `CHECK` and `OFFSET` vary.
+const MONAD_BLOCK: [&str; 18] = [
+    "inp w",
+    "mul x 0",
+    "add x z",
+    "mod x 26",
+    "div z DIV",
+    "add x CHECK",
+    "eql x w",
+    "eql x 0",
+    "mul y 0",
+    "add y 25",
+    "mul y x",
+    "add y 1",
+    "mul z y",
+    "mul y 0",
+    "add y w",
+    "add y OFFSET",
+    "mul y x",
+    "add z y",
+];
+
+/// Largest `OFFSET` that keeps every pushed digit plus offset below 26.
+const MAX_OFFSET: i64 = 25 - 9;
+
+/// A MONAD listing decoded into the constraints it puts on pairs of digits.
+///
+/// MONAD treats z as a stack of base 26 digits. A block dividing z by 1 can never
+/// pass its check with a digit from 1 to 9, so it pushes its digit plus `OFFSET`,
+/// which must stay a base 26 digit itself for the stack to work.
+/// A block dividing by 26 pops the top, and unless its digit equals the popped value
+/// plus `CHECK` it pushes again. With as many pops as pushes, z only ends up at 0
+/// when every pop matches, so each one ties its digit to the digit that was pushed.
+struct Monad {
+    /// Digit positions `(i, j)` along with the `diff` such that `digit[j] = digit[i] + diff`.
+    pairs: Vec<(usize, usize, i64)>,
+    len: usize,
+}
+
+impl Monad {
+    /// Recognizes a MONAD listing, returning `None` for any other program.
+    fn decode(program: &[Instr]) -> Option<Self> {
+        let (prologue, blocks) = split_blocks(program);
+
+        if !prologue.is_empty() {
+            return None;
+        }
+
+        let mut stack = vec![];
+        let mut pairs = vec![];
+
+        for (j, block) in blocks.iter().enumerate() {
+            let [div, check, offset] = monad_constants(block)?;
+
+            match div {
+                1 if check > 9 && (0..=MAX_OFFSET).contains(&offset) => stack.push((j, offset)),
+                26 => {
+                    let (i, pushed_offset) = stack.pop()?;
+                    pairs.push((i, j, pushed_offset + check));
+                }
+                _ => return None,
+            }
+        }
+
+        let len = blocks.len();
+
+        stack.is_empty().then_some(Self { pairs, len })
+    }
+
+    /// Finds the first accepted model number trying digits in the given order at every position.
+    fn find(&self, digit_order: &[i64]) -> Option<u64> {
+        let mut digits = vec![0; self.len];
+
+        for &(i, j, diff) in &self.pairs {
+            let first = digit_order
+                .iter()
+                .copied()
+                .find(|d| (1..=9).contains(&(d + diff)))?;
+
+            digits[i] = first;
+            digits[j] = first + diff;
+        }
+
+        Some(digits.iter().fold(0, |n, &d| n * 10 + d as u64))
+    }
+}
+
+/// Matches a block against [`MONAD_BLOCK`], returning its `DIV`, `CHECK` and `OFFSET`.
+fn monad_constants(block: &[Instr]) -> Option<[i64; 3]> {
+    if block.len() != MONAD_BLOCK.len() {
+        return None;
+    }
+
+    let mut constants = [0; 3];
+
+    for (instr, pattern) in block.iter().zip(MONAD_BLOCK) {
+        let text = instr.to_string();
+        let (pattern_head, placeholder) = pattern.rsplit_once(' ')?;
+        let slot = ["DIV", "CHECK", "OFFSET"]
+            .iter()
+            .position(|&p| p == placeholder);
+
+        match (slot, instr) {
+            (None, _) if text == pattern => {}
+            (Some(slot), Instr::Op(_, _, Operand::Num(n))) if text.starts_with(pattern_head) => {
+                constants[slot] = *n;
+            }
+            _ => return None,
+        }
+    }
+
+    Some(constants)
+}
+
+/// Finds the first model number accepted by the program trying digits in the given order,
+/// decoding it as MONAD when possible and searching otherwise.
+fn find_model_number(program: &[Instr], digit_order: &[i64]) -> Option<u64> {
+    match Monad::decode(program) {
+        Some(monad) => monad.find(digit_order),
+        None => Solver::new(program).find(digit_order),
+    }
+}
+
+/// Searches for model numbers accepted by a program reading one digit at a time.
+///
+/// The program is cut into blocks, each starting with an `inp`, and the search
+/// tries digits block by block. Register states from which no digits lead to
+/// acceptance are remembered, ignoring registers the rest of the program
+/// overwrites before reading, since many different paths lead to the same state.
+struct Solver<'a> {
+    prologue: &'a [Instr],
+    blocks: Vec<&'a [Instr]>,
+    /// Registers read by each block or later ones before being overwritten.
+    live: Vec<[bool; 4]>,
+    dead_ends: FxHashSet<(usize, Registers)>,
+}
+
+impl<'a> Solver<'a> {
+    fn new(program: &'a [Instr]) -> Self {
+        let (prologue, blocks) = split_blocks(program);
+
+        // the verdict only depends on z at the end
+        let mut live_after = [false, false, false, true];
+        let mut live = vec![[false; 4]; blocks.len()];
+
+        for (block, live_in) in blocks.iter().zip(&mut live).rev() {
+            for instr in block.iter().rev() {
+                live_after[instr.writes()] = false;
+
+                for reg in instr.reads() {
+                    live_after[reg] = true;
+                }
+            }
+
+            *live_in = live_after;
+        }
+
+        Self {
+            prologue,
+            blocks,
+            live,
+            dead_ends: FxHashSet::default(),
+        }
+    }
+
+    /// Finds the first accepted model number trying digits in the given order at every position.
+    fn find(&mut self, digit_order: &[i64]) -> Option<u64> {
+        let mut regs = Registers::default();
+        run(self.prologue, &mut regs, &[]).ok()?;
+
+        let mut digits = vec![];
+        self.dead_ends.clear();
+
+        self.search(0, regs, digit_order, &mut digits)
+            .then(|| digits.iter().fold(0, |n, &d| n * 10 + d as u64))
+    }
+
+    fn search(
+        &mut self,
+        block: usize,
+        mut regs: Registers,
+        digit_order: &[i64],
+        digits: &mut Vec<i64>,
+    ) -> bool {
+        if block == self.blocks.len() {
+            return regs[Z] == 0;
+        }
+
+        for (reg, &live) in regs.iter_mut().zip(&self.live[block]) {
+            if !live {
+                *reg = 0;
+            }
+        }
+
+        if self.dead_ends.contains(&(block, regs)) {
+            return false;
+        }
+
+        for &digit in digit_order {
+            let mut next = regs;
+
+            if run(self.blocks[block], &mut next, &[digit]).is_err() {
+                continue;
+            }
+
+            digits.push(digit);
+
+            if self.search(block + 1, next, digit_order, digits) {
+                return true;
+            }
+
+            digits.pop();
+        }
+
+        self.dead_ends.insert((block, regs));
+
+        false
+    }
+}
+
+fn trace(program: &[Instr], model: &ModelNumber) {
+    let mut regs = Registers::default();
+    let mut input = model.0.iter().copied();
+
+    eprintln!("Checking model number {}:", model.0.iter().join(""));
+
+    for (i, instr) in program.iter().enumerate() {
+        if let Err(fault) = instr.execute(&mut regs, &mut input) {
+            eprintln!(
+                "  {:>4}: {:<12} failed: {}",
+                i + 1,
+                instr.to_string(),
+                fault
+            );
+            return;
+        }
+
+        let values = REGISTERS
+            .iter()
+            .zip(regs)
+            .map(|(name, value)| format!("{}={}", name, value));
+
+        eprintln!(
+            "  {:>4}: {:<12} {}",
+            i + 1,
+            instr.to_string(),
+            values.format(" ")
+        );
+    }
+
+    if regs[Z] == 0 {
+        eprintln!("Accepted");
+    } else {
+        eprintln!("Rejected with z={}", regs[Z]);
+    }
+}
+
+#[anyhoo::anyhoo]
+fn main() {
+    let (program, params) = parse_input()?;
+
+    report::try_measure_and_print(1, || {
+        find_model_number(&program, &LARGEST_FIRST).ok_or(NoModelNumber)
+    })?;
+
+    report::try_measure_and_print(2, || {
+        find_model_number(&program, &SMALLEST_FIRST).ok_or(NoModelNumber)
+    })?;
+
+    if let Some(model) = params.get_option("trace") {
+        trace(&program, &model);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Builds a MONAD listing from the `(DIV, CHECK, OFFSET)` of each block.
+    fn monad(blocks: &[(i64, i64, i64)]) -> Vec<Instr> {
+        let lines = blocks
+            .iter()
+            .flat_map(|&(div, check, offset)| {
+                MONAD_BLOCK.map(|line| {
+                    line.replace("DIV", &div.to_string())
+                        .replace("CHECK", &check.to_string())
+                        .replace("OFFSET", &offset.to_string())
+                })
+            })
+            .collect_vec();
+
+        parse::lines(&lines)
+            .map(|line| parse_instr(line).unwrap())
+            .collect()
+    }
+
+    // digit[2] = digit[1] + 2 and digit[3] = digit[0] + 1
+    const BLOCKS: [(i64, i64, i64); 4] = [(1, 12, 4), (1, 11, 7), (26, -5, 0), (26, -3, 1)];
+
+    #[test]
+    fn decoded_monad() {
+        let program = monad(&BLOCKS);
+
+        assert!(Monad::decode(&program).is_some());
+        assert_eq!(find_model_number(&program, &LARGEST_FIRST), Some(8799));
+        assert_eq!(find_model_number(&program, &SMALLEST_FIRST), Some(1132));
+    }
+
+    #[test]
+    fn solver_agrees_with_decoding() {
+        let program = monad(&BLOCKS);
+
+        assert_eq!(Solver::new(&program).find(&LARGEST_FIRST), Some(8799));
+        assert_eq!(Solver::new(&program).find(&SMALLEST_FIRST), Some(1132));
+    }
+
+    #[test]
+    fn offsets_beyond_base_26_are_searched() {
+        let program = monad(&[(1, 12, 20), (26, -22, 0)]);
+
+        // pairing the digits would give 97, but 9 + 20 carries into the next base 26 digit
+        assert!(Monad::decode(&program).is_none());
+        assert_eq!(find_model_number(&program, &LARGEST_FIRST), Some(53));
+    }
+
+    #[test]
+    fn no_model_number() {
+        let program = monad(&[(1, 12, 8), (26, 9, 0)]);
+
+        assert_eq!(find_model_number(&program, &LARGEST_FIRST), None);
+    }
+
+    #[test]
+    fn overflows_are_faults() {
+        let program = parse::lines(&["inp w", "add w 9223372036854775807", "mul w 2"])
+            .map(|line| parse_instr(line).unwrap())
+            .collect_vec();
+
+        assert_eq!(run(&program[..2], &mut [0; 4], &[0]), Ok(()));
+        assert_eq!(
+            run(&program[..2], &mut [0; 4], &[1]),
+            Err(Fault::Overflow(Op::Add, 1, i64::MAX))
+        );
+        assert_eq!(
+            run(&program, &mut [0; 4], &[0]),
+            Err(Fault::Overflow(Op::Mul, i64::MAX, 2))
+        );
+
+        let mut regs = [i64::MIN, -1, 0, 0];
+        let div = parse_instr(parse::lines(&["div w x"]).next().unwrap()).unwrap();
+        assert_eq!(
+            run(&[div], &mut regs, &[]),
+            Err(Fault::Overflow(Op::Div, i64::MIN, -1))
+        );
+    }
+}
@@ -0,0 +1,201 @@
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::fs;
+use std::path::PathBuf;
+
+use aoc::grid::{self, Grid, Pos};
+use aoc::input::Input;
+use aoc::params::{Param, Params};
+use aoc::parse;
+use aoc::report;
+
+#[anyhoo::anyhoo]
+fn parse_input() -> (Seafloor, Params) {
+    let (input, params) = Input::parse_args_with(
+        25,
+        "Day 25: Sea Cucumber",
+        &[Param::option::<PathBuf>(
+            "frames",
+            "DIR",
+            "Write the seafloor after every step to DIR/stepNNNN.txt",
+        )],
+    )?;
+
+    let lines = input.read_lines()?;
+
+    let seafloor = grid::parse_cells(parse::lines(&lines), "`>`, `v` or `.`", cell)?;
+
+    (seafloor, params)
+}
+
+fn cell(c: char) -> Option<Cell> {
+    match c {
+        '>' => Some(Cell::East),
+        'v' => Some(Cell::South),
+        '.' => Some(Cell::Empty),
+        _ => None,
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+enum Cell {
+    Empty,
+    East,
+    South,
+}
+
+/// The seafloor wraps around at its edges in both directions.
+type Seafloor = Grid<Cell>;
+
+/// The step at which the seafloor got back to an earlier state, so the herds never stop.
+#[derive(Clone, Copy, Debug)]
+struct NeverSettles(usize);
+
+impl Display for Cell {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let c = match self {
+            Cell::Empty => '.',
+            Cell::East => '>',
+            Cell::South => 'v',
+        };
+
+        write!(f, "{}", c)
+    }
+}
+
+/// Moves every sea cucumber of the `herd` facing an empty cell, all at once.
+///
+/// Returns the number of sea cucumbers that moved.
+fn move_herd(seafloor: &mut Seafloor, herd: Cell) -> usize {
+    let (width, height) = (seafloor.width(), seafloor.height());
+    let ahead = |(x, y): Pos| match herd {
+        Cell::East => ((x + 1) % width, y),
+        _ => (x, (y + 1) % height),
+    };
+
+    let moving: Vec<_> = seafloor
+        .iter()
+        .filter(|&(pos, &cell)| cell == herd && seafloor[ahead(pos)] == Cell::Empty)
+        .map(|(pos, _)| pos)
+        .collect();
+
+    for &pos in &moving {
+        seafloor[pos] = Cell::Empty;
+        seafloor[ahead(pos)] = herd;
+    }
+
+    moving.len()
+}
+
+/// Moves the east-facing herd and then the south-facing one, returning whether anything moved.
+fn step(seafloor: &mut Seafloor) -> bool {
+    let east = move_herd(seafloor, Cell::East);
+    let south = move_herd(seafloor, Cell::South);
+
+    east + south > 0
+}
+
+/// Iterates over the seafloor from its initial state up to the first step
+/// where the sea cucumbers stop moving, excluding that step.
+///
+/// Ends with an error instead if the seafloor gets back to an earlier state.
+fn frames(seafloor: &Seafloor) -> impl Iterator<Item = Result<Seafloor, NeverSettles>> {
+    let mut next = Some(seafloor.clone());
+    let mut steps = 0;
+    // Brent's cycle detection: rather than every state, keep only the one
+    // reached at the latest power of two steps, which any cycle comes back to
+    let mut saved = None;
+
+    std::iter::from_fn(move || {
+        let current = next.take()?;
+
+        if saved.as_ref() == Some(&current) {
+            return Some(Err(NeverSettles(steps)));
+        }
+        if steps == 0 || usize::is_power_of_two(steps) {
+            saved = Some(current.clone());
+        }
+
+        let mut following = current.clone();
+        next = step(&mut following).then_some(following);
+        steps += 1;
+
+        Some(Ok(current))
+    })
+}
+
+impl Display for NeverSettles {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "The sea cucumbers never stop moving, step {} repeats an earlier one",
+            self.0
+        )
+    }
+}
+
+impl Error for NeverSettles {}
+
+#[anyhoo::anyhoo]
+fn main() {
+    let (seafloor, params) = parse_input()?;
+
+    // written first, so that herds which never settle can be inspected up to the repeat
+    if let Some(dir) = params.get_option::<PathBuf>("frames") {
+        fs::create_dir_all(&dir)?;
+
+        for (step, frame) in frames(&seafloor).enumerate() {
+            fs::write(
+                dir.join(format!("step{:04}.txt", step)),
+                format!("{}\n", frame?),
+            )?;
+        }
+    }
+
+    // the initial state takes the place of the step where nothing moves
+    report::try_measure_and_print(1, || {
+        frames(&seafloor).try_fold(0, |n, frame| frame.map(|_| n + 1))
+    })?;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_seafloor(lines: &[&str]) -> Seafloor {
+        grid::parse_cells(parse::lines(lines), "`>`, `v` or `.`", cell).unwrap()
+    }
+
+    fn steps_to_settle(seafloor: &Seafloor) -> Result<usize, NeverSettles> {
+        frames(seafloor).try_fold(0, |n, frame| frame.map(|_| n + 1))
+    }
+
+    #[test]
+    fn example() {
+        let seafloor = parse_seafloor(&[
+            "v...>>.vv>",
+            ".vv>>.vv..",
+            ">>.>v>...v",
+            ">>v>>.>.v.",
+            "v>v.vv.v..",
+            ">.>>..v...",
+            ".vv..>.>v.",
+            "v.v..>>v.v",
+            "....v..v.>",
+        ]);
+
+        assert_eq!(steps_to_settle(&seafloor).unwrap(), 58);
+    }
+
+    #[test]
+    fn herds_going_around_never_settle() {
+        assert!(matches!(
+            steps_to_settle(&parse_seafloor(&[">."])),
+            Err(NeverSettles(_))
+        ));
+
+        // the cycle is longer than the first powers of two, and starts after a few steps
+        let seafloor = parse_seafloor(&[">>.......", ".........", "v........"]);
+        assert!(matches!(steps_to_settle(&seafloor), Err(NeverSettles(_))));
+    }
+}
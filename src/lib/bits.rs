@@ -1,4 +1,9 @@
 //! The BITS decoder utilities.
+//!
+//! Malformed transmissions are reported as [`ParseError`]s on the single line
+//! of puzzle input, at the column of the hex digit holding the offending bits.
+
+use crate::parse::ParseError;
 
 const VERSION_BITS: usize = 3;
 const PACKET_TYPE_BITS: usize = 3;
@@ -61,10 +66,14 @@ enum Packet {
 }
 
 /// Transforms a bit-by-bit iterator into a stream of [`Token`]s.
+///
+/// The stream ends after the outermost packet, or after the first error.
 pub struct Lexer<I> {
     it: I,
     state: Next,
     stack: Vec<Remaining>,
+    // number of bits read so far
+    position: usize,
 }
 
 /// Fully decodes and evaluates the expression in a BITS message.
@@ -142,14 +151,25 @@ impl<I: Iterator<Item = u8>> Lexer<I> {
             it: bits.into_iter(),
             state: Next::Version,
             stack: vec![Remaining::Packets(1)],
+            position: 0,
+        }
+    }
+
+    fn next_token(&mut self) -> Result<Option<(Token, usize)>, ParseError> {
+        let result = self.read_token();
+
+        if result.is_err() {
+            self.state = Next::None;
         }
+
+        result
     }
 
-    fn next_token(&mut self) -> Option<(Token, usize)> {
+    fn read_token(&mut self) -> Result<Option<(Token, usize)>, ParseError> {
         let (result, bits_read, next_state) = match self.state {
-            Next::Version => (self.read_version(), VERSION_BITS, Next::PacketType),
+            Next::Version => (self.read_version()?, VERSION_BITS, Next::PacketType),
             Next::PacketType => {
-                let token = self.read_packet_type();
+                let token = self.read_packet_type()?;
                 let type_id = token.unwrap_type_id();
 
                 let next = match type_id {
@@ -160,19 +180,33 @@ impl<I: Iterator<Item = u8>> Lexer<I> {
                 (token, PACKET_TYPE_BITS, next)
             }
             Next::Length => {
-                let length_type = self.read_bits(LENGTH_TYPE_BITS);
+                let length_type = self.read_bits(LENGTH_TYPE_BITS)?;
+                let column = self.column();
 
                 let (token, bits_read) = if length_type == 1 {
-                    let t = self.read_length_subpackets();
+                    let t = self.read_length_subpackets()?;
                     let len = t.unwrap_len_subpackets();
 
+                    if len == 0 {
+                        return Err(ParseError::new(1, column, "at least one subpacket", "0"));
+                    }
+
                     self.stack.push(Remaining::Packets(len));
 
                     (t, LENGTH_TYPE_BITS + LEN_SUBPACKETS_BITS)
                 } else {
-                    let t = self.read_length_bitwise();
+                    let t = self.read_length_bitwise()?;
                     let len = t.unwrap_len_bitwise();
 
+                    if len == 0 {
+                        return Err(ParseError::new(
+                            1,
+                            column,
+                            "at least one subpacket bit",
+                            "0",
+                        ));
+                    }
+
                     self.stack.push(Remaining::Bits(len));
 
                     (t, LENGTH_TYPE_BITS + LEN_BITWISE_BITS)
@@ -181,7 +215,7 @@ impl<I: Iterator<Item = u8>> Lexer<I> {
                 (token, bits_read, Next::Version)
             }
             Next::LitFragment => {
-                let token = self.read_lit_fragment();
+                let token = self.read_lit_fragment()?;
                 let (is_cont, _) = token.unwrap_lit_fragment();
 
                 let next = if is_cont {
@@ -199,63 +233,87 @@ impl<I: Iterator<Item = u8>> Lexer<I> {
                 (token, LIT_FRAGMENT_BITS, next)
             }
             Next::None => {
-                return None;
+                return Ok(None);
             }
         };
 
         self.state = next_state;
 
-        Some((result, bits_read))
+        Ok(Some((result, bits_read)))
+    }
+
+    // column of the hex digit holding the next bit to read
+    fn column(&self) -> usize {
+        self.position / 4 + 1
     }
 
-    // read and return at most `n` bits (up to 16)
-    fn read_bits(&mut self, n: usize) -> u16 {
+    // read and return exactly `n` bits (up to 16),
+    // failing if the transmission or the enclosing packet ends first
+    fn read_bits(&mut self, n: usize) -> Result<u16, ParseError> {
         assert!(n <= 16, "Attempted to read more than 16 bits at once");
 
+        let column = self.column();
+
         for rem in &mut self.stack {
             if let Remaining::Bits(b) = rem {
-                // *b -= n as u16;
-                *b = b.checked_sub(n as u16).unwrap();
+                *b = b.checked_sub(n as u16).ok_or_else(|| {
+                    ParseError::new(
+                        1,
+                        column,
+                        format!("a subpacket within the remaining {} bits", b),
+                        "a longer subpacket",
+                    )
+                })?;
             }
         }
 
-        self.it
-            .by_ref()
-            .take(n)
-            .fold(0, |acc, bit| acc << 1 | (bit & 0x1) as u16)
+        let (read, value) = self.it.by_ref().take(n).fold((0, 0), |(read, acc), bit| {
+            (read + 1, acc << 1 | (bit & 0x1) as u16)
+        });
+
+        self.position += read;
+
+        if read < n {
+            return Err(ParseError {
+                column,
+                ..ParseError::end_of_input(1, format!("{} more bit(s)", n))
+            });
+        }
+
+        Ok(value)
     }
 
-    fn read_version(&mut self) -> Token {
-        let bits = self.read_bits(VERSION_BITS) as u8;
+    fn read_version(&mut self) -> Result<Token, ParseError> {
+        let bits = self.read_bits(VERSION_BITS)? as u8;
 
-        Token::Version(bits)
+        Ok(Token::Version(bits))
     }
 
-    fn read_packet_type(&mut self) -> Token {
-        let bits = self.read_bits(PACKET_TYPE_BITS) as u8;
+    fn read_packet_type(&mut self) -> Result<Token, ParseError> {
+        let bits = self.read_bits(PACKET_TYPE_BITS)? as u8;
 
-        Token::PacketType(bits)
+        Ok(Token::PacketType(bits))
     }
 
-    fn read_lit_fragment(&mut self) -> Token {
-        let bits = self.read_bits(LIT_FRAGMENT_BITS) as u8;
+    fn read_lit_fragment(&mut self) -> Result<Token, ParseError> {
+        let bits = self.read_bits(LIT_FRAGMENT_BITS)? as u8;
 
         let is_continuation = (bits & 0b10000) != 0;
         let value = bits & 0xf;
 
-        Token::LiteralFragment(is_continuation, value)
+        Ok(Token::LiteralFragment(is_continuation, value))
     }
 
-    fn read_length_bitwise(&mut self) -> Token {
-        let bits = self.read_bits(LEN_BITWISE_BITS);
+    fn read_length_bitwise(&mut self) -> Result<Token, ParseError> {
+        let bits = self.read_bits(LEN_BITWISE_BITS)?;
 
-        Token::LengthBitwise(bits)
+        Ok(Token::LengthBitwise(bits))
     }
 
-    fn read_length_subpackets(&mut self) -> Token {
-        let bits = self.read_bits(LEN_SUBPACKETS_BITS);
+    fn read_length_subpackets(&mut self) -> Result<Token, ParseError> {
+        let bits = self.read_bits(LEN_SUBPACKETS_BITS)?;
 
-        Token::LengthSubpackets(bits)
+        Ok(Token::LengthSubpackets(bits))
     }
 
     fn try_collapse_stack(&mut self) {
@@ -286,16 +344,21 @@ impl<I: Iterator<Item = u8>> Decoder<I> {
     }
 
     /// Consumes the decoder to evaluate the expression.
-    pub fn decode(mut self) -> u64 {
+    ///
+    /// Fails if the transmission is malformed.
+    pub fn decode(mut self) -> Result<u64, ParseError> {
         loop {
-            if let Some(result) = self.decode_next_token() {
-                return result;
+            if let Some(result) = self.decode_next_token()? {
+                return Ok(result);
             }
         }
     }
 
-    fn decode_next_token(&mut self) -> Option<u64> {
-        let (token, bits) = self.lexer.next_token().unwrap();
+    fn decode_next_token(&mut self) -> Result<Option<u64>, ParseError> {
+        let (token, bits) = self
+            .lexer
+            .next_token()?
+            .expect("Lexer finished before the outermost packet");
         let mut should_collapse = false;
 
         match token {
@@ -341,7 +404,7 @@ impl<I: Iterator<Item = u8>> Decoder<I> {
             return self.collapse_stack();
         }
 
-        None
+        Ok(None)
     }
 
     fn apply_read_bits(&mut self, bits: usize) {
@@ -355,7 +418,7 @@ impl<I: Iterator<Item = u8>> Decoder<I> {
         }
     }
 
-    fn collapse_stack(&mut self) -> Option<u64> {
+    fn collapse_stack(&mut self) -> Result<Option<u64>, ParseError> {
         use Packet::*;
 
         let mut value = None;
@@ -376,10 +439,10 @@ impl<I: Iterator<Item = u8>> Decoder<I> {
                         }
                         Some(Remaining::Packets(n)) => {
                             *n -= 1;
-                            return None;
+                            return Ok(None);
                         }
                         Some(Remaining::Bits(_)) => {
-                            return None;
+                            return Ok(None);
                         }
                         _ => unreachable!(),
                     }
@@ -396,10 +459,10 @@ impl<I: Iterator<Item = u8>> Decoder<I> {
                         }
                         Some(Remaining::Packets(n)) => {
                             *n -= 1;
-                            return None;
+                            return Ok(None);
                         }
                         Some(Remaining::Bits(_)) => {
-                            return None;
+                            return Ok(None);
                         }
                         _ => unreachable!(),
                     }
@@ -416,10 +479,10 @@ impl<I: Iterator<Item = u8>> Decoder<I> {
                         }
                         Some(Remaining::Packets(n)) => {
                             *n -= 1;
-                            return None;
+                            return Ok(None);
                         }
                         Some(Remaining::Bits(_)) => {
-                            return None;
+                            return Ok(None);
                         }
                         _ => unreachable!(),
                     }
@@ -436,10 +499,10 @@ impl<I: Iterator<Item = u8>> Decoder<I> {
                         }
                         Some(Remaining::Packets(n)) => {
                             *n -= 1;
-                            return None;
+                            return Ok(None);
                         }
                         Some(Remaining::Bits(_)) => {
-                            return None;
+                            return Ok(None);
                         }
                         _ => unreachable!(),
                     }
@@ -453,17 +516,21 @@ impl<I: Iterator<Item = u8>> Decoder<I> {
                         Some(Remaining::Packets(1) | Remaining::Bits(0)) => {
                             let result = match values.as_slice() {
                                 [a, b] => (a > b) as u64,
-                                _ => panic!("Incorrect number of operands for '>'"),
+                                _ => {
+                                    let column = self.lexer.column();
+
+                                    return Err(operand_error(column, ">", values.len()));
+                                }
                             };
 
                             value = Some(result);
                         }
                         Some(Remaining::Packets(n)) => {
                             *n -= 1;
-                            return None;
+                            return Ok(None);
                         }
                         Some(Remaining::Bits(_)) => {
-                            return None;
+                            return Ok(None);
                         }
                         _ => unreachable!(),
                     }
@@ -477,17 +544,21 @@ impl<I: Iterator<Item = u8>> Decoder<I> {
                         Some(Remaining::Packets(1) | Remaining::Bits(0)) => {
                             let result = match values.as_slice() {
                                 [a, b] => (a < b) as u64,
-                                _ => panic!("Incorrect number of operands for '<'"),
+                                _ => {
+                                    let column = self.lexer.column();
+
+                                    return Err(operand_error(column, "<", values.len()));
+                                }
                             };
 
                             value = Some(result);
                         }
                         Some(Remaining::Packets(n)) => {
                             *n -= 1;
-                            return None;
+                            return Ok(None);
                         }
                         Some(Remaining::Bits(_)) => {
-                            return None;
+                            return Ok(None);
                         }
                         _ => unreachable!(),
                     }
@@ -501,17 +572,21 @@ impl<I: Iterator<Item = u8>> Decoder<I> {
                         Some(Remaining::Packets(1) | Remaining::Bits(0)) => {
                             let result = match values.as_slice() {
                                 [a, b] => (a == b) as u64,
-                                _ => panic!("Incorrect number of operands for '=='"),
+                                _ => {
+                                    let column = self.lexer.column();
+
+                                    return Err(operand_error(column, "==", values.len()));
+                                }
                             };
 
                             value = Some(result);
                         }
                         Some(Remaining::Packets(n)) => {
                             *n -= 1;
-                            return None;
+                            return Ok(None);
                         }
                         Some(Remaining::Bits(_)) => {
-                            return None;
+                            return Ok(None);
                         }
                         _ => unreachable!(),
                     }
@@ -519,7 +594,7 @@ impl<I: Iterator<Item = u8>> Decoder<I> {
                 None => {
                     assert!(value.is_some(), "Stack fully unwrapped without a value");
 
-                    return value;
+                    return Ok(value);
                 }
             }
 
@@ -528,10 +603,21 @@ impl<I: Iterator<Item = u8>> Decoder<I> {
     }
 }
 
+fn operand_error(column: usize, operator: &str, found: usize) -> ParseError {
+    ParseError::new(
+        1,
+        column,
+        format!("2 operands for '{}'", operator),
+        format!("{} operands", found),
+    )
+}
+
 impl<I: Iterator<Item = u8>> Iterator for Lexer<I> {
-    type Item = Token;
+    type Item = Result<Token, ParseError>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.next_token().map(|(token, _)| token)
+        self.next_token()
+            .transpose()
+            .map(|token| token.map(|(token, _)| token))
     }
 }
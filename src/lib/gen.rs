@@ -0,0 +1,907 @@
+//! Seeded random puzzle inputs for stress-testing and benchmarking the solutions.
+//!
+//! Every generator takes size knobs as arguments and writes input in the
+//! exact format of its day. The [`GENERATORS`] registry exposes them with
+//! their knobs declared as [`Param`]s, as used by `aoc gen`.
+
+use std::fmt::Write;
+
+use itertools::Itertools;
+use rand::rngs::StdRng;
+use rand::seq::{index, SliceRandom};
+use rand::{Rng, SeedableRng};
+
+use crate::geom3::{Point, Rotation};
+use crate::ocr::{self, GLYPH_HEIGHT, GLYPH_WIDTH};
+use crate::params::{Param, Params};
+
+/// A day's input generator along with its size knobs.
+pub struct Generator {
+    pub day: u32,
+    /// What the input consists of, e.g. `sonar depths`.
+    pub format: &'static str,
+    knobs: fn() -> Vec<Param>,
+    generate: fn(&mut StdRng, &Params) -> Result<String, String>,
+}
+
+/// Generators of all supported days, ordered by day.
+pub const GENERATORS: &[Generator] = &[
+    Generator {
+        day: 1,
+        format: "sonar depths",
+        knobs: || {
+            vec![Param::new::<usize>(
+                "depths",
+                2000,
+                "Number of depth readings",
+            )]
+        },
+        generate: |rng, p| Ok(sonar_depths(rng, p.get("depths"))),
+    },
+    Generator {
+        day: 2,
+        format: "submarine commands",
+        knobs: || vec![Param::new::<usize>("commands", 1000, "Number of commands")],
+        generate: |rng, p| Ok(commands(rng, p.get("commands"))),
+    },
+    Generator {
+        day: 3,
+        format: "bit strings",
+        knobs: || {
+            vec![
+                Param::new::<usize>("lines", 1000, "Number of distinct bit strings"),
+                Param::new::<usize>("width", 12, "Bits per string"),
+            ]
+        },
+        generate: |rng, p| bit_strings(rng, p.get("lines"), p.get("width")),
+    },
+    Generator {
+        day: 4,
+        format: "bingo cards",
+        knobs: || {
+            vec![
+                Param::new::<usize>("boards", 100, "Number of boards"),
+                Param::new::<usize>("numbers", 100, "Numbers drawn, at least 25"),
+            ]
+        },
+        generate: |rng, p| bingo(rng, p.get("boards"), p.get("numbers")),
+    },
+    Generator {
+        day: 5,
+        format: "vent lines",
+        knobs: || {
+            vec![
+                Param::new::<usize>("lines", 500, "Number of vent lines"),
+                Param::new::<u32>("size", 1000, "Width and height of the ocean floor"),
+            ]
+        },
+        generate: |rng, p| vent_lines(rng, p.get("lines"), p.get("size")),
+    },
+    Generator {
+        day: 9,
+        format: "height map",
+        knobs: || grid_knobs(100, 100),
+        generate: |rng, p| Ok(digit_grid(rng, p.get("width"), p.get("height"), 0)),
+    },
+    Generator {
+        day: 10,
+        format: "bracket lines",
+        knobs: || {
+            vec![
+                Param::new::<usize>("lines", 100, "Number of lines"),
+                Param::new::<usize>("length", 110, "Maximum line length"),
+            ]
+        },
+        generate: |rng, p| Ok(bracket_lines(rng, p.get("lines"), p.get("length"))),
+    },
+    Generator {
+        day: 11,
+        format: "octopus energy levels",
+        knobs: || grid_knobs(10, 10),
+        generate: |rng, p| Ok(digit_grid(rng, p.get("width"), p.get("height"), 0)),
+    },
+    Generator {
+        day: 12,
+        format: "cave graph",
+        knobs: || {
+            vec![
+                Param::new::<usize>("small", 6, "Number of small caves"),
+                Param::new::<usize>("big", 3, "Number of big caves"),
+                Param::new::<usize>("edges", 20, "Number of tunnels"),
+            ]
+        },
+        generate: |rng, p| cave_graph(rng, p.get("small"), p.get("big"), p.get("edges")),
+    },
+    Generator {
+        day: 13,
+        format: "dots and fold instructions",
+        knobs: || {
+            vec![
+                Param::new::<usize>("letters", 8, "Letters revealed by the folds"),
+                Param::new::<usize>("folds", 12, "Number of folds"),
+            ]
+        },
+        generate: |rng, p| Ok(origami(rng, p.get("letters"), p.get("folds"))),
+    },
+    Generator {
+        day: 14,
+        format: "polymer template and rules",
+        knobs: || {
+            vec![
+                Param::new::<usize>("template", 20, "Length of the polymer template"),
+                Param::new::<usize>("elements", 10, "Number of elements, at most 26"),
+            ]
+        },
+        generate: |rng, p| polymer(rng, p.get("template"), p.get("elements")),
+    },
+    Generator {
+        day: 15,
+        format: "risk levels",
+        knobs: || grid_knobs(100, 100),
+        generate: |rng, p| Ok(digit_grid(rng, p.get("width"), p.get("height"), 1)),
+    },
+    Generator {
+        day: 16,
+        format: "BITS hex transmission",
+        knobs: || {
+            vec![Param::new::<usize>(
+                "literals",
+                100,
+                "Number of literal packets",
+            )]
+        },
+        generate: |rng, p| Ok(transmission(rng, p.get("literals"))),
+    },
+    Generator {
+        day: 18,
+        format: "snailfish numbers",
+        knobs: || {
+            vec![Param::new::<usize>(
+                "numbers",
+                100,
+                "Number of snailfish numbers",
+            )]
+        },
+        generate: |rng, p| Ok(snailfish_numbers(rng, p.get("numbers"))),
+    },
+    Generator {
+        day: 19,
+        format: "scanner reports",
+        knobs: || {
+            vec![
+                Param::new::<usize>("scanners", 30, "Number of scanners"),
+                Param::new::<usize>("beacons", 26, "Beacons per scanner, at least 12"),
+            ]
+        },
+        generate: |rng, p| scanner_reports(rng, p.get("scanners"), p.get("beacons")),
+    },
+    Generator {
+        day: 22,
+        format: "reactor reboot steps",
+        knobs: || {
+            vec![
+                Param::new::<usize>("steps", 420, "Number of reboot steps"),
+                Param::new::<i32>("extent", 100_000, "Largest absolute coordinate").at_least(1),
+            ]
+        },
+        generate: |rng, p| Ok(reboot_steps(rng, p.get("steps"), p.get("extent"))),
+    },
+];
+
+// sum, min, max
+const VARIADIC_PACKET_IDS: [u64; 3] = [0, 2, 3];
+// sum, product, min, max, gt, lt, eq
+const BINARY_PACKET_IDS: [u64; 7] = [0, 1, 2, 3, 5, 6, 7];
+const LIT_PACKET_ID: u64 = 4;
+const MAX_BITWISE_LEN: usize = (1 << 15) - 1;
+const MAX_SUBPACKETS: usize = (1 << 11) - 1;
+
+const BRACKETS: [(char, char); 4] = [('(', ')'), ('[', ']'), ('{', '}'), ('<', '>')];
+/// Chunks left open at once, which keeps completion scores of day 10 well within a u64.
+const MAX_OPEN_CHUNKS: usize = 20;
+
+/// Snailfish numbers in the input are reduced, so pairs are nested at most this deep.
+const SNAILFISH_DEPTH: u32 = 4;
+
+/// Beacons each scanner shares with an earlier one, the overlap sought on day 19.
+const SCANNER_OVERLAP: usize = 12;
+/// Distance along each axis within which a scanner detects beacons.
+const SCANNER_RANGE: i64 = 1000;
+
+/// Reboot steps within this distance from the origin come first, as in the puzzle.
+const INIT_REGION: i32 = 50;
+const INIT_STEPS: usize = 20;
+
+impl Generator {
+    /// Declarations of the size knobs, with defaults close to the puzzle's input.
+    pub fn knobs(&self) -> Vec<Param> {
+        (self.knobs)()
+    }
+
+    /// Generates an input with knob values resolved against [`Generator::knobs`].
+    ///
+    /// Fails on knob values the input format cannot accommodate.
+    pub fn generate(&self, seed: u64, params: &Params) -> Result<String, String> {
+        let mut rng = StdRng::seed_from_u64(seed);
+
+        (self.generate)(&mut rng, params)
+    }
+}
+
+/// Returns the input generator of `day`, if there is one.
+pub fn for_day(day: u32) -> Option<&'static Generator> {
+    GENERATORS.iter().find(|g| g.day == day)
+}
+
+fn grid_knobs(width: usize, height: usize) -> Vec<Param> {
+    vec![
+        Param::new::<usize>("width", width, "Number of columns"),
+        Param::new::<usize>("height", height, "Number of rows"),
+    ]
+}
+
+/// A slowly drifting sequence of depths, as read by the sonar of day 1.
+pub fn sonar_depths<R: Rng + ?Sized>(rng: &mut R, depths: usize) -> String {
+    let mut depth: i32 = rng.gen_range(100..200);
+    let mut result = String::new();
+
+    for _ in 0..depths {
+        writeln!(result, "{}", depth).unwrap();
+        depth = (depth + rng.gen_range(-5..=10)).max(0);
+    }
+
+    result
+}
+
+/// Submarine commands like `forward 5`, as steered on day 2.
+pub fn commands<R: Rng + ?Sized>(rng: &mut R, commands: usize) -> String {
+    let mut result = String::new();
+
+    for _ in 0..commands {
+        // mostly forward, and diving more than surfacing
+        let direction = match rng.gen_range(0..10) {
+            0..=4 => "forward",
+            5..=7 => "down",
+            _ => "up",
+        };
+
+        writeln!(result, "{} {}", direction, rng.gen_range(1..=9)).unwrap();
+    }
+
+    result
+}
+
+/// Distinct bit strings of `width` bits, as diagnosed on day 3.
+pub fn bit_strings<R: Rng + ?Sized>(
+    rng: &mut R,
+    lines: usize,
+    width: usize,
+) -> Result<String, String> {
+    if !(1..usize::BITS as usize).contains(&width) || lines > 1 << width {
+        return Err(format!(
+            "Cannot draw {} distinct strings of {} bits",
+            lines, width
+        ));
+    }
+
+    let mut result = String::new();
+
+    for value in index::sample(rng, 1 << width, lines) {
+        writeln!(result, "{:0width$b}", value, width = width).unwrap();
+    }
+
+    Ok(result)
+}
+
+/// Comma-separated draws followed by 5x5 bingo boards, as played on day 4.
+///
+/// Every number in `0..numbers` is drawn, so every board eventually wins.
+pub fn bingo<R: Rng + ?Sized>(
+    rng: &mut R,
+    boards: usize,
+    numbers: usize,
+) -> Result<String, String> {
+    const BOARD_SIZE: usize = 5;
+
+    if numbers < BOARD_SIZE * BOARD_SIZE {
+        return Err(format!("Cannot fill a board with {} numbers", numbers));
+    }
+
+    let mut draws: Vec<_> = (0..numbers).collect();
+    draws.shuffle(rng);
+
+    let mut result = draws.iter().join(",");
+    let width = (numbers - 1).to_string().len();
+
+    for _ in 0..boards {
+        let board = index::sample(rng, numbers, BOARD_SIZE * BOARD_SIZE).into_vec();
+
+        result.push('\n');
+
+        for row in board.chunks(BOARD_SIZE) {
+            let row = row.iter().map(|n| format!("{:>width$}", n, width = width));
+
+            writeln!(result, "{}", row.collect::<Vec<_>>().join(" ")).unwrap();
+        }
+    }
+
+    Ok(result)
+}
+
+/// Horizontal, vertical and diagonal lines of vents like `0,9 -> 5,9`, as mapped on day 5.
+pub fn vent_lines<R: Rng + ?Sized>(rng: &mut R, lines: usize, size: u32) -> Result<String, String> {
+    if size < 2 {
+        return Err(format!("Cannot draw lines on a floor of size {}", size));
+    }
+
+    let mut result = String::new();
+
+    for _ in 0..lines {
+        let (x1, y1) = (rng.gen_range(0..size), rng.gen_range(0..size));
+        let other = |rng: &mut R, c| loop {
+            let d = rng.gen_range(0..size);
+
+            if d != c {
+                break d;
+            }
+        };
+
+        let (x2, y2) = match rng.gen_range(0..3) {
+            0 => (other(rng, x1), y1),
+            1 => (x1, other(rng, y1)),
+            _ => {
+                let room = |c, forward| if forward { size - 1 - c } else { c };
+                // head away from the floor's edge when there is no room towards it
+                let direction = |rng: &mut R, c| {
+                    let forward = rng.gen_bool(0.5);
+
+                    forward != (room(c, forward) == 0)
+                };
+                let (right, down) = (direction(rng, x1), direction(rng, y1));
+
+                let len = rng.gen_range(1..=room(x1, right).min(room(y1, down)));
+                let step = |c, forward| if forward { c + len } else { c - len };
+
+                (step(x1, right), step(y1, down))
+            }
+        };
+
+        writeln!(result, "{},{} -> {},{}", x1, y1, x2, y2).unwrap();
+    }
+
+    Ok(result)
+}
+
+/// A grid of digits from `min_digit` to 9, as used by days 9, 11 and 15.
+pub fn digit_grid<R: Rng + ?Sized>(
+    rng: &mut R,
+    width: usize,
+    height: usize,
+    min_digit: u32,
+) -> String {
+    let mut result = String::with_capacity((width + 1) * height);
+
+    for _ in 0..height {
+        for _ in 0..width {
+            let digit = rng.gen_range(min_digit..=9);
+
+            result.push(char::from_digit(digit, 10).unwrap());
+        }
+
+        result.push('\n');
+    }
+
+    result
+}
+
+/// Lines of brackets that are either corrupted or incomplete, as checked on day 10.
+///
+/// An odd number of lines is incomplete, so that their scores have a middle one.
+pub fn bracket_lines<R: Rng + ?Sized>(rng: &mut R, lines: usize, length: usize) -> String {
+    let half = lines / 2;
+    let n_incomplete = if half % 2 == 1 || half == lines {
+        half
+    } else {
+        half + 1
+    };
+
+    let mut incomplete: Vec<_> = (0..lines).map(|i| i < n_incomplete).collect();
+    incomplete.shuffle(rng);
+
+    let mut result = String::new();
+
+    for is_incomplete in incomplete {
+        let len = rng.gen_range(length / 2..=length.max(1));
+        let mut stack = vec![];
+        let mut line = String::with_capacity(len);
+
+        let valid_chunks = |rng: &mut R, stack: &mut Vec<char>, line: &mut String, n| {
+            for _ in 0..n {
+                match stack.last() {
+                    Some(&close) if stack.len() >= MAX_OPEN_CHUNKS || rng.gen_bool(0.45) => {
+                        line.push(close);
+                        stack.pop();
+                    }
+                    _ => {
+                        let &(open, close) = BRACKETS.choose(rng).unwrap();
+
+                        line.push(open);
+                        stack.push(close);
+                    }
+                }
+            }
+        };
+
+        if is_incomplete {
+            valid_chunks(rng, &mut stack, &mut line, len);
+
+            if stack.is_empty() {
+                line.push('(');
+            }
+        } else {
+            let corrupt_at = rng.gen_range(1..=len.max(1));
+            valid_chunks(rng, &mut stack, &mut line, corrupt_at - 1);
+
+            let expected = match stack.last() {
+                Some(&close) => close,
+                None => {
+                    line.push('(');
+                    ')'
+                }
+            };
+            let wrong = BRACKETS.iter().map(|&(_, close)| close);
+            let wrong: Vec<_> = wrong.filter(|&c| c != expected).collect();
+
+            line.push(*wrong.choose(rng).unwrap());
+
+            // anything goes after the first illegal character
+            for _ in corrupt_at..len {
+                let &(open, close) = BRACKETS.choose(rng).unwrap();
+
+                line.push(if rng.gen_bool(0.5) { open } else { close });
+            }
+        }
+
+        result.push_str(&line);
+        result.push('\n');
+    }
+
+    result
+}
+
+/// Tunnels between `start`, `end`, small and big caves, as explored on day 12.
+///
+/// Big caves are never connected to each other, which would allow endless paths.
+pub fn cave_graph<R: Rng + ?Sized>(
+    rng: &mut R,
+    small: usize,
+    big: usize,
+    edges: usize,
+) -> Result<String, String> {
+    let names = |rng: &mut R, n, letters: &[u8]| {
+        let pairs = letters.len() * letters.len();
+
+        if n > pairs {
+            return Err(format!("Cannot name {} caves with two letters", n));
+        }
+
+        let sampled = index::sample(rng, pairs, n).into_iter();
+
+        Ok(sampled
+            .map(|i| {
+                let name = [letters[i / letters.len()], letters[i % letters.len()]];
+
+                String::from_utf8_lossy(&name).into_owned()
+            })
+            .collect::<Vec<_>>())
+    };
+
+    let small = names(rng, small, b"abcdefghijklmnopqrstuvwxyz")?;
+    let big = names(rng, big, b"ABCDEFGHIJKLMNOPQRSTUVWXYZ")?;
+    let caves: Vec<&str> = ["start", "end"]
+        .into_iter()
+        .chain(small.iter().map(String::as_str))
+        .chain(big.iter().map(String::as_str))
+        .collect();
+    let is_big = |i: usize| i >= 2 + small.len();
+
+    let mut tunnels: Vec<_> = (0..caves.len())
+        .flat_map(|a| (a + 1..caves.len()).map(move |b| (a, b)))
+        .filter(|&(a, b)| !(is_big(a) && is_big(b)))
+        .collect();
+
+    if edges < 2 || edges > tunnels.len() {
+        return Err(format!(
+            "Cannot connect {} caves with {} tunnels (at least 2, at most {})",
+            caves.len(),
+            edges,
+            tunnels.len()
+        ));
+    }
+
+    tunnels.shuffle(rng);
+
+    // make sure there is a way in and a way out
+    for cave in [0, 1] {
+        let touches = |&&(a, b): &&(usize, usize)| a == cave || b == cave;
+
+        if !tunnels[..cave].iter().any(|t| touches(&t)) {
+            let i = cave + tunnels[cave..].iter().position(|t| touches(&t)).unwrap();
+
+            tunnels.swap(cave, i);
+        }
+    }
+
+    tunnels.truncate(edges);
+    tunnels.shuffle(rng);
+
+    let mut result = String::new();
+
+    for (a, b) in tunnels {
+        let (a, b) = if rng.gen_bool(0.5) { (a, b) } else { (b, a) };
+
+        writeln!(result, "{}-{}", caves[a], caves[b]).unwrap();
+    }
+
+    Ok(result)
+}
+
+/// Dots on transparent paper that spell `letters` random letters
+/// after the listed folds, as read on day 13.
+///
+/// The paper doubles in size with every fold, starting with one along `x`.
+pub fn origami<R: Rng + ?Sized>(rng: &mut R, letters: usize, folds: usize) -> String {
+    let mut dots = vec![];
+
+    for i in 0..letters {
+        let &(_, glyph) = ocr::FONT.choose(rng).unwrap();
+        let lit = glyph.bytes().enumerate().filter(|&(_, b)| b == b'#');
+
+        dots.extend(lit.map(|(j, _)| (i * (GLYPH_WIDTH + 1) + j % GLYPH_WIDTH, j / GLYPH_WIDTH)));
+    }
+
+    let (mut width, mut height) = (letters * (GLYPH_WIDTH + 1), GLYPH_HEIGHT);
+    let mut fold_lines = Vec::with_capacity(folds);
+
+    // unfold the paper, moving each dot to either side of the fold or both
+    for fold in (0..folds).rev() {
+        let along_x = fold % 2 == 0;
+        let line = if along_x { width } else { height };
+
+        dots = dots
+            .into_iter()
+            .flat_map(|(x, y)| {
+                let mirrored = if along_x {
+                    (2 * line - x, y)
+                } else {
+                    (x, 2 * line - y)
+                };
+
+                match rng.gen_range(0..10) {
+                    0..=4 => vec![(x, y)],
+                    5..=8 => vec![mirrored],
+                    _ => vec![(x, y), mirrored],
+                }
+            })
+            .collect();
+
+        if along_x {
+            width = 2 * width + 1;
+        } else {
+            height = 2 * height + 1;
+        }
+
+        fold_lines.push((if along_x { 'x' } else { 'y' }, line));
+    }
+
+    dots.sort_unstable();
+    dots.dedup();
+    dots.shuffle(rng);
+
+    let mut result = String::new();
+
+    for (x, y) in dots {
+        writeln!(result, "{},{}", x, y).unwrap();
+    }
+
+    result.push('\n');
+
+    for (axis, line) in fold_lines.into_iter().rev() {
+        writeln!(result, "fold along {}={}", axis, line).unwrap();
+    }
+
+    result
+}
+
+/// A polymer template and an insertion rule for every pair of elements, as grown on day 14.
+pub fn polymer<R: Rng + ?Sized>(
+    rng: &mut R,
+    template: usize,
+    elements: usize,
+) -> Result<String, String> {
+    let mut alphabet: Vec<_> = ('A'..='Z').collect();
+
+    if !(1..=alphabet.len()).contains(&elements) {
+        return Err(format!("Cannot name {} elements with letters", elements));
+    }
+
+    alphabet.shuffle(rng);
+    let elements = &alphabet[..elements];
+
+    let mut result: String = (0..template)
+        .map(|_| elements.choose(rng).unwrap())
+        .collect();
+    result.push_str("\n\n");
+
+    for a in elements {
+        for b in elements {
+            writeln!(result, "{}{} -> {}", a, b, elements.choose(rng).unwrap()).unwrap();
+        }
+    }
+
+    Ok(result)
+}
+
+/// A hex encoded BITS transmission with exactly `literals` literal packets, as decoded on day 16.
+pub fn transmission<R: Rng + ?Sized>(rng: &mut R, literals: usize) -> String {
+    let hexes = bits_to_hexes(packet_bits(rng, literals.max(1)));
+    let mut result: String = hexes
+        .into_iter()
+        .map(|hex| {
+            char::from_digit(hex.into(), 16)
+                .unwrap()
+                .to_ascii_uppercase()
+        })
+        .collect();
+
+    result.push('\n');
+    result
+}
+
+/// Generates the bits of a random packet tree with exactly `n_literals` literal packets.
+///
+/// # Panics
+///
+/// Panics if `n_literals` is 0.
+pub fn packet_bits<R: Rng + ?Sized>(rng: &mut R, n_literals: usize) -> Vec<u8> {
+    let mut bits = vec![];
+
+    if n_literals == 1 {
+        encode_literal(rng, &mut bits);
+        return bits;
+    }
+
+    // comparisons and products only get two operands to stay well-defined
+    let (type_id, n_children) = if n_literals == 2 {
+        let id = BINARY_PACKET_IDS[rng.gen_range(0..BINARY_PACKET_IDS.len())];
+
+        (id, 2)
+    } else {
+        let id = VARIADIC_PACKET_IDS[rng.gen_range(0..VARIADIC_PACKET_IDS.len())];
+
+        (id, rng.gen_range(2..=std::cmp::min(n_literals, 16)))
+    };
+
+    let mut remaining = n_literals;
+    let subpackets: Vec<_> = (0..n_children)
+        .map(|i| {
+            let left = n_children - i - 1;
+            let take = if left == 0 {
+                remaining
+            } else {
+                rng.gen_range(1..=remaining - left)
+            };
+
+            remaining -= take;
+            packet_bits(rng, take)
+        })
+        .collect();
+
+    encode_operator(rng, &mut bits, type_id, &subpackets);
+
+    bits
+}
+
+/// Generates the bits of a chain of single-operand sums nested `depth` levels deep.
+pub fn deep_packet_bits<R: Rng + ?Sized>(rng: &mut R, depth: usize) -> Vec<u8> {
+    let mut bits = vec![];
+    encode_literal(rng, &mut bits);
+
+    for _ in 0..depth {
+        let mut wrapped = vec![];
+        encode_operator(rng, &mut wrapped, 0, &[bits]);
+        bits = wrapped;
+    }
+
+    bits
+}
+
+/// Packs bits into hex digit values, zero padding the last one.
+pub fn bits_to_hexes(mut bits: Vec<u8>) -> Vec<u8> {
+    let padding = (4 - bits.len() % 4) % 4;
+    bits.resize(bits.len() + padding, 0);
+
+    bits.chunks(4)
+        .map(|c| c.iter().fold(0, |acc, bit| acc << 1 | bit))
+        .collect()
+}
+
+fn push_bits(bits: &mut Vec<u8>, value: u64, n: usize) {
+    bits.extend((0..n).rev().map(|i| (value >> i & 0x1) as u8));
+}
+
+fn encode_literal<R: Rng + ?Sized>(rng: &mut R, bits: &mut Vec<u8>) {
+    push_bits(bits, rng.gen_range(0..8), 3);
+    push_bits(bits, LIT_PACKET_ID, 3);
+
+    let n_fragments = rng.gen_range(1..=4);
+    for i in 0..n_fragments {
+        let is_cont = (i + 1 < n_fragments) as u64;
+
+        push_bits(bits, is_cont << 4 | rng.gen_range(0..16), 5);
+    }
+}
+
+fn encode_operator<R: Rng + ?Sized>(
+    rng: &mut R,
+    bits: &mut Vec<u8>,
+    type_id: u64,
+    subpackets: &[Vec<u8>],
+) {
+    push_bits(bits, rng.gen_range(0..8), 3);
+    push_bits(bits, type_id, 3);
+
+    let total_len: usize = subpackets.iter().map(Vec::len).sum();
+
+    // pick the length encoding at random, unless one of them would overflow
+    let bitwise = match (
+        total_len <= MAX_BITWISE_LEN,
+        subpackets.len() <= MAX_SUBPACKETS,
+    ) {
+        (true, true) => rng.gen(),
+        (bitwise, _) => bitwise,
+    };
+
+    if bitwise {
+        push_bits(bits, 0, 1);
+        push_bits(bits, total_len as u64, 15);
+    } else {
+        push_bits(bits, 1, 1);
+        push_bits(bits, subpackets.len() as u64, 11);
+    }
+
+    for sub in subpackets {
+        bits.extend_from_slice(sub);
+    }
+}
+
+/// Randomly nested snailfish numbers, as added up on day 18.
+pub fn snailfish_numbers<R: Rng + ?Sized>(rng: &mut R, numbers: usize) -> String {
+    let mut result = String::new();
+
+    for _ in 0..numbers {
+        write_snailfish_pair(rng, &mut result, 1);
+        result.push('\n');
+    }
+
+    result
+}
+
+fn write_snailfish_pair<R: Rng + ?Sized>(rng: &mut R, result: &mut String, depth: u32) {
+    result.push('[');
+
+    for i in 0..2 {
+        if i > 0 {
+            result.push(',');
+        }
+
+        if depth < SNAILFISH_DEPTH && rng.gen_bool(0.6) {
+            write_snailfish_pair(rng, result, depth + 1);
+        } else {
+            result.push(char::from_digit(rng.gen_range(0..10), 10).unwrap());
+        }
+    }
+
+    result.push(']');
+}
+
+/// Beacon reports of scanners in random orientations, as aligned on day 19.
+///
+/// Every scanner after the first shares 12 of its beacons with an earlier one,
+/// so that all of them can be aligned with the first.
+pub fn scanner_reports<R: Rng + ?Sized>(
+    rng: &mut R,
+    scanners: usize,
+    beacons: usize,
+) -> Result<String, String> {
+    if beacons < SCANNER_OVERLAP {
+        return Err(format!(
+            "Scanners need at least {} beacons to overlap, got {}",
+            SCANNER_OVERLAP, beacons
+        ));
+    }
+
+    let rotations = Rotation::all().collect_vec();
+    let near = |rng: &mut R, center: Point| {
+        let mut coord = || rng.gen_range(-SCANNER_RANGE..=SCANNER_RANGE);
+
+        Point::new(center.x + coord(), center.y + coord(), center.z + coord())
+    };
+
+    // positions and beacons of the scanners placed so far, in the first one's frame
+    let mut placed: Vec<(Point, Vec<Point>)> = vec![];
+    let mut result = String::new();
+
+    for i in 0..scanners {
+        let (position, mut seen) = match placed.choose(rng) {
+            Some((earlier, earlier_seen)) => {
+                let shared = earlier_seen.choose_multiple(rng, SCANNER_OVERLAP);
+
+                (near(rng, *earlier), shared.copied().collect_vec())
+            }
+            None => (Point::default(), vec![]),
+        };
+
+        while seen.len() < beacons {
+            seen.push(near(rng, position));
+        }
+
+        seen.shuffle(rng);
+
+        if i > 0 {
+            result.push('\n');
+        }
+
+        writeln!(result, "--- scanner {} ---", i).unwrap();
+
+        let rotation = rotations.choose(rng).unwrap();
+
+        for &beacon in &seen {
+            let [x, y, z] = rotation.apply(beacon - position).coords();
+
+            writeln!(result, "{},{},{}", x, y, z).unwrap();
+        }
+
+        placed.push((position, seen));
+    }
+
+    Ok(result)
+}
+
+/// Reboot steps turning cuboids on and off, as executed on day 22.
+///
+/// The first 20 steps stay within the initialization region around the origin.
+pub fn reboot_steps<R: Rng + ?Sized>(rng: &mut R, steps: usize, extent: i32) -> String {
+    let mut result = String::new();
+
+    for i in 0..steps {
+        let (bound, max_size) = if i < INIT_STEPS || extent <= INIT_REGION {
+            (INIT_REGION.min(extent), INIT_REGION / 2)
+        } else {
+            (extent, extent / 4)
+        };
+        let range = |rng: &mut R| {
+            let from = rng.gen_range(-bound..=bound);
+            let to = (from + rng.gen_range(0..=max_size.max(1))).min(bound);
+
+            (from, to)
+        };
+
+        let (x, y, z) = (range(rng), range(rng), range(rng));
+        // the first step always turns something on
+        let state = if i == 0 || rng.gen_bool(0.6) {
+            "on"
+        } else {
+            "off"
+        };
+
+        writeln!(
+            result,
+            "{} x={}..{},y={}..{},z={}..{}",
+            state, x.0, x.1, y.0, y.1, z.0, z.1
+        )
+        .unwrap();
+    }
+
+    result
+}
@@ -0,0 +1,353 @@
+//! Three-dimensional integer geometry.
+//!
+//! Besides points and boxes, this covers lining up two scans of the same points
+//! taken from different positions and orientations: [`align`] searches the 24
+//! rotations for one that makes enough points coincide after a translation.
+
+use std::cmp::Ordering;
+use std::ops::{Add, Sub};
+
+use itertools::{iproduct, Itertools};
+use rustc_hash::FxHashMap;
+
+/// A point, or the offset between two points.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+/// A box of points, including both corners.
+///
+/// `min` never lies beyond `max` on any axis, so a cuboid always holds at least one point.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Cuboid {
+    min: Point,
+    max: Point,
+}
+
+/// A rotation by multiples of 90 degrees around the axes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Rotation {
+    rows: [[i64; 3]; 3],
+}
+
+/// A rotation followed by a translation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Transform {
+    pub rotation: Rotation,
+    pub offset: Point,
+}
+
+/// The squared distances between all pairs of points in a scan, sorted.
+///
+/// Distances do not depend on where the scan was taken from, so two scans
+/// can only share `n` points if their fingerprints share `n * (n - 1) / 2` distances.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Fingerprint {
+    distances: Vec<i64>,
+}
+
+impl Point {
+    pub const fn new(x: i64, y: i64, z: i64) -> Self {
+        Self { x, y, z }
+    }
+
+    pub fn coords(self) -> [i64; 3] {
+        [self.x, self.y, self.z]
+    }
+
+    pub fn from_coords([x, y, z]: [i64; 3]) -> Self {
+        Self { x, y, z }
+    }
+
+    pub fn manhattan(self, other: Self) -> i64 {
+        let d = self - other;
+
+        d.x.abs() + d.y.abs() + d.z.abs()
+    }
+
+    pub fn squared_distance(self, other: Self) -> i64 {
+        let d = self - other;
+
+        d.x * d.x + d.y * d.y + d.z * d.z
+    }
+
+    fn zip_with<F: Fn(i64, i64) -> i64>(self, other: Self, f: F) -> Self {
+        Self::new(f(self.x, other.x), f(self.y, other.y), f(self.z, other.z))
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.zip_with(rhs, |a, b| a + b)
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.zip_with(rhs, |a, b| a - b)
+    }
+}
+
+impl Cuboid {
+    /// Creates the box between two corners, or returns `None` if `min` lies beyond `max`.
+    pub fn new(min: Point, max: Point) -> Option<Self> {
+        (min.x <= max.x && min.y <= max.y && min.z <= max.z).then_some(Self { min, max })
+    }
+
+    /// The cube spanning `-bound..=bound` on each axis.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bound` is negative.
+    pub fn around_origin(bound: i64) -> Self {
+        assert!(bound >= 0, "The bound of a cube must not be negative");
+
+        Self {
+            min: Point::new(-bound, -bound, -bound),
+            max: Point::new(bound, bound, bound),
+        }
+    }
+
+    pub fn min(&self) -> Point {
+        self.min
+    }
+
+    pub fn max(&self) -> Point {
+        self.max
+    }
+
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let min = self.min.zip_with(other.min, std::cmp::max);
+        let max = self.max.zip_with(other.max, std::cmp::min);
+
+        Self::new(min, max)
+    }
+
+    /// Number of points inside the box.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the box holds more than `u128::MAX` points.
+    pub fn volume(&self) -> u128 {
+        let side = |min: i64, max: i64| (i128::from(max) - i128::from(min) + 1) as u128;
+
+        side(self.min.x, self.max.x)
+            .checked_mul(side(self.min.y, self.max.y))
+            .and_then(|area| area.checked_mul(side(self.min.z, self.max.z)))
+            .expect("Cuboid volume overflows")
+    }
+}
+
+impl Rotation {
+    pub const IDENTITY: Self = Self {
+        rows: [[1, 0, 0], [0, 1, 0], [0, 0, 1]],
+    };
+
+    /// All 24 orientations, starting with the identity.
+    ///
+    /// These are the axis permutations with sign flips that keep the handedness,
+    /// i.e. the signed permutation matrices with a determinant of 1.
+    pub fn all() -> impl Iterator<Item = Self> {
+        let permutations = (0..3usize).permutations(3);
+        let signs = iproduct!([1, -1], [1, -1], [1, -1]);
+
+        iproduct!(permutations, signs)
+            .map(|(axes, (sx, sy, sz))| {
+                let mut rows = [[0; 3]; 3];
+
+                for (row, (axis, sign)) in rows.iter_mut().zip(axes.into_iter().zip([sx, sy, sz])) {
+                    row[axis] = sign;
+                }
+
+                Self { rows }
+            })
+            .filter(|rotation| rotation.determinant() == 1)
+    }
+
+    pub fn apply(&self, point: Point) -> Point {
+        let coords = point.coords();
+
+        Point::from_coords(
+            self.rows
+                .map(|row| row.iter().zip(coords).map(|(a, b)| a * b).sum()),
+        )
+    }
+
+    /// The rotation applying `inner` first and then `self`.
+    pub fn compose(&self, inner: &Self) -> Self {
+        let mut rows = [[0; 3]; 3];
+
+        for (i, j) in iproduct!(0..3, 0..3) {
+            rows[i][j] = (0..3).map(|k| self.rows[i][k] * inner.rows[k][j]).sum();
+        }
+
+        Self { rows }
+    }
+
+    fn determinant(&self) -> i64 {
+        let [a, b, c] = self.rows;
+
+        a[0] * (b[1] * c[2] - b[2] * c[1]) - a[1] * (b[0] * c[2] - b[2] * c[0])
+            + a[2] * (b[0] * c[1] - b[1] * c[0])
+    }
+}
+
+impl Transform {
+    pub const IDENTITY: Self = Self {
+        rotation: Rotation::IDENTITY,
+        offset: Point::new(0, 0, 0),
+    };
+
+    pub fn apply(&self, point: Point) -> Point {
+        self.rotation.apply(point) + self.offset
+    }
+
+    /// The transform applying `inner` first and then `self`.
+    pub fn compose(&self, inner: &Self) -> Self {
+        Self {
+            rotation: self.rotation.compose(&inner.rotation),
+            offset: self.apply(inner.offset),
+        }
+    }
+}
+
+impl Fingerprint {
+    pub fn new(points: &[Point]) -> Self {
+        let distances = points
+            .iter()
+            .tuple_combinations()
+            .map(|(&a, &b)| a.squared_distance(b))
+            .sorted_unstable()
+            .collect();
+
+        Self { distances }
+    }
+
+    /// Counts the distances found in both fingerprints, with multiplicity.
+    pub fn shared(&self, other: &Self) -> usize {
+        let (mut i, mut j, mut shared) = (0, 0, 0);
+
+        while let (Some(a), Some(b)) = (self.distances.get(i), other.distances.get(j)) {
+            match a.cmp(b) {
+                Ordering::Less => i += 1,
+                Ordering::Greater => j += 1,
+                Ordering::Equal => {
+                    shared += 1;
+                    i += 1;
+                    j += 1;
+                }
+            }
+        }
+
+        shared
+    }
+
+    /// Whether the scans may share `overlap` points, judging by their distances alone.
+    pub fn may_overlap(&self, other: &Self, overlap: usize) -> bool {
+        self.shared(other) >= overlap * overlap.saturating_sub(1) / 2
+    }
+}
+
+/// Finds a transform mapping at least `overlap` of `points` onto `reference`.
+///
+/// Tries every rotation, counting how many pairs of points agree on each translation.
+/// Returns `None` if no rotation lines up enough points.
+pub fn align(reference: &[Point], points: &[Point], overlap: usize) -> Option<Transform> {
+    Rotation::all().find_map(|rotation| {
+        let rotated = points.iter().map(|&p| rotation.apply(p)).collect_vec();
+        let mut votes = FxHashMap::<Point, usize>::default();
+
+        for (&r, &p) in iproduct!(reference, &rotated) {
+            let count = votes.entry(r - p).or_default();
+            *count += 1;
+
+            if *count >= overlap {
+                return Some(Transform {
+                    rotation,
+                    offset: r - p,
+                });
+            }
+        }
+
+        None
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    #[test]
+    fn rotations() {
+        let rotations = Rotation::all().collect_vec();
+
+        assert_eq!(rotations.len(), 24);
+        assert_eq!(rotations[0], Rotation::IDENTITY);
+
+        // a point with distinct coordinates ends up somewhere else under each rotation
+        let point = Point::new(1, 2, 3);
+        let images: HashSet<_> = rotations.iter().map(|r| r.apply(point)).collect();
+
+        assert_eq!(images.len(), 24);
+
+        for (a, b) in iproduct!(&rotations, &rotations) {
+            assert!(rotations.contains(&a.compose(b)));
+        }
+    }
+
+    #[test]
+    fn align_recovers_transform() {
+        let reference = [
+            Point::new(404, -588, -901),
+            Point::new(528, -643, 409),
+            Point::new(-838, 591, 734),
+            Point::new(390, -675, -793),
+            Point::new(-537, -823, -458),
+            Point::new(-485, -357, 347),
+        ];
+        let hidden = Transform {
+            rotation: Rotation::all().nth(17).unwrap(),
+            offset: Point::new(68, -1246, -43),
+        };
+
+        // the same points as seen from a scanner placed by `hidden`, plus one only it sees
+        let inverse = Rotation::all()
+            .find(|r| r.compose(&hidden.rotation) == Rotation::IDENTITY)
+            .unwrap();
+        let mut points = reference
+            .iter()
+            .map(|&p| inverse.apply(p - hidden.offset))
+            .collect_vec();
+        points.push(Point::new(1000, 1000, 1000));
+
+        assert_eq!(align(&reference, &points, 6), Some(hidden));
+        assert_eq!(align(&reference, &points, 7), None);
+    }
+
+    #[test]
+    fn fingerprints_share_distances() {
+        let a = Fingerprint::new(&[
+            Point::new(0, 0, 0),
+            Point::new(1, 0, 0),
+            Point::new(0, 2, 0),
+        ]);
+        let b = Fingerprint::new(&[
+            Point::new(5, 5, 5),
+            Point::new(5, 5, 6),
+            Point::new(9, 9, 9),
+        ]);
+
+        assert_eq!(a.shared(&b), 1);
+        assert!(a.may_overlap(&b, 2));
+        assert!(!a.may_overlap(&b, 3));
+    }
+}
@@ -0,0 +1,368 @@
+//! Dense two-dimensional grids.
+
+use std::fmt::{self, Display};
+use std::ops::{Index, IndexMut};
+
+use itertools::Itertools;
+use ndarray::{Array2, ArrayView1};
+
+use crate::parse::{self, ParseError};
+
+/// Grid position as `(x, y)`, with `(0, 0)` in the top left corner.
+pub type Pos = (usize, usize);
+
+const ORTHOGONAL: [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+const ALL_AROUND: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// Which cells count as adjacent to a cell.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Connectivity {
+    /// The 4 orthogonally adjacent cells.
+    Four,
+    /// The 8 surrounding cells, including diagonals.
+    Eight,
+}
+
+/// A rectangular grid of cells stored in row-major order.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Array2<T>,
+}
+
+/// Parses lines of decimal digits into a grid, e.g. a height or risk map.
+pub fn parse_digits<S: AsRef<str>>(lines: &[S]) -> Result<Grid<u32>, ParseError> {
+    let mut lines = parse::lines(lines);
+
+    let first = lines
+        .next()
+        .ok_or_else(|| ParseError::end_of_input(1, "a row of digits"))?;
+    let mut cells = first.digits(10)?;
+    let width = cells.len();
+
+    if width == 0 {
+        return Err(first.error(first.text(), "a row of digits"));
+    }
+    let mut height = 1;
+
+    for line in lines {
+        let row = line.digits(10)?;
+
+        if row.len() != width {
+            return Err(line.mismatch(format!("a row of {} digits", width)));
+        }
+
+        cells.extend(row);
+        height += 1;
+    }
+
+    Ok(Grid::from_shape_vec(width, height, cells))
+}
+
+/// Parses lines of characters into a grid, reading each character with `cell`.
+///
+/// Characters for which `cell` returns `None` are reported as not being `expected`.
+pub fn parse_cells<'a, T, F>(
+    lines: impl IntoIterator<Item = parse::Line<'a>>,
+    expected: &str,
+    mut cell: F,
+) -> Result<Grid<T>, ParseError>
+where
+    F: FnMut(char) -> Option<T>,
+{
+    let mut lines = lines.into_iter();
+    let mut parse_row = |line: parse::Line<'a>| -> Result<Vec<T>, ParseError> {
+        let text = line.text();
+
+        text.char_indices()
+            .map(|(i, c)| cell(c).ok_or_else(|| line.error(&text[i..i + c.len_utf8()], expected)))
+            .collect()
+    };
+
+    let first = lines
+        .next()
+        .ok_or_else(|| ParseError::end_of_input(1, format!("a row of {}", expected)))?;
+    let mut cells = parse_row(first)?;
+    let width = cells.len();
+
+    if width == 0 {
+        return Err(first.error(first.text(), format!("a row of {}", expected)));
+    }
+    let mut height = 1;
+
+    for line in lines {
+        let row = parse_row(line)?;
+
+        if row.len() != width {
+            return Err(line.mismatch(format!("a row of {} cells", width)));
+        }
+
+        cells.extend(row);
+        height += 1;
+    }
+
+    Ok(Grid::from_shape_vec(width, height, cells))
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid from cells listed in row-major order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of cells is not `width * height`.
+    pub fn from_shape_vec(width: usize, height: usize, cells: Vec<T>) -> Self {
+        let cells = Array2::from_shape_vec((height, width), cells)
+            .expect("Number of cells does not match the grid size");
+
+        Self { cells }
+    }
+
+    /// Creates a grid filled with copies of a single value.
+    pub fn from_elem(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            cells: Array2::from_elem((height, width), value),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.cells.ncols()
+    }
+
+    pub fn height(&self) -> usize {
+        self.cells.nrows()
+    }
+
+    /// Returns the cell at `pos`, or `None` if it lies outside the grid.
+    pub fn get(&self, (x, y): Pos) -> Option<&T> {
+        self.cells.get((y, x))
+    }
+
+    /// Returns the cell at `pos` mutably, or `None` if it lies outside the grid.
+    pub fn get_mut(&mut self, (x, y): Pos) -> Option<&mut T> {
+        self.cells.get_mut((y, x))
+    }
+
+    /// Iterates over all positions in row-major order.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width();
+
+        (0..self.height()).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Iterates over all cells along with their positions, in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.cells.indexed_iter().map(|((y, x), v)| ((x, y), v))
+    }
+
+    /// Iterates mutably over all cells along with their positions, in row-major order.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Pos, &mut T)> {
+        self.cells.indexed_iter_mut().map(|((y, x), v)| ((x, y), v))
+    }
+
+    /// Iterates over the up to 4 positions orthogonally adjacent to `pos`.
+    pub fn neighbors4(&self, pos: Pos) -> impl Iterator<Item = Pos> {
+        self.neighbors_by(pos, &ORTHOGONAL)
+    }
+
+    /// Iterates over the up to 8 positions adjacent to `pos`, including diagonals.
+    pub fn neighbors8(&self, pos: Pos) -> impl Iterator<Item = Pos> {
+        self.neighbors_by(pos, &ALL_AROUND)
+    }
+
+    /// Iterates over the positions adjacent to `pos` under the given connectivity.
+    pub fn neighbors(&self, pos: Pos, connectivity: Connectivity) -> impl Iterator<Item = Pos> {
+        let offsets: &'static [_] = match connectivity {
+            Connectivity::Four => &ORTHOGONAL,
+            Connectivity::Eight => &ALL_AROUND,
+        };
+
+        self.neighbors_by(pos, offsets)
+    }
+
+    /// Spreads outwards from `starts`, offering every neighbour of a reached cell to `enter`.
+    ///
+    /// A neighbour is reached in turn whenever `enter` returns `true`, so `enter`
+    /// decides both what the fill may cross and whether a cell is revisited.
+    pub fn flood_fill<F>(
+        &mut self,
+        starts: impl IntoIterator<Item = Pos>,
+        connectivity: Connectivity,
+        mut enter: F,
+    ) where
+        F: FnMut(Pos, &mut T) -> bool,
+    {
+        let mut pending = starts.into_iter().collect_vec();
+
+        while let Some(pos) = pending.pop() {
+            for neighbor in self.neighbors(pos, connectivity) {
+                if enter(neighbor, &mut self[neighbor]) {
+                    pending.push(neighbor);
+                }
+            }
+        }
+    }
+
+    /// Returns a view of row `y`.
+    pub fn row(&self, y: usize) -> ArrayView1<'_, T> {
+        self.cells.row(y)
+    }
+
+    /// Returns a view of column `x`.
+    pub fn column(&self, x: usize) -> ArrayView1<'_, T> {
+        self.cells.column(x)
+    }
+
+    /// Iterates over views of all rows, top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = ArrayView1<'_, T>> {
+        self.cells.rows().into_iter()
+    }
+
+    /// Iterates over views of all columns, left to right.
+    pub fn columns(&self) -> impl Iterator<Item = ArrayView1<'_, T>> {
+        self.cells.columns().into_iter()
+    }
+
+    /// Creates a grid of the same size by applying `f` to every cell.
+    pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> Grid<U> {
+        Grid {
+            cells: self.cells.map(f),
+        }
+    }
+
+    /// Returns the underlying array, indexed by `[y, x]`.
+    pub fn as_array(&self) -> &Array2<T> {
+        &self.cells
+    }
+
+    fn neighbors_by(
+        &self,
+        (x, y): Pos,
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = Pos> {
+        let (width, height) = (self.width() as isize, self.height() as isize);
+        let (x, y) = (x as isize, y as isize);
+
+        offsets.iter().filter_map(move |(dx, dy)| {
+            let (nx, ny) = (x + dx, y + dy);
+
+            let in_bounds = (0..width).contains(&nx) && (0..height).contains(&ny);
+            in_bounds.then_some((nx as usize, ny as usize))
+        })
+    }
+}
+
+impl<T> From<Array2<T>> for Grid<T> {
+    /// Wraps an array indexed by `[y, x]`.
+    fn from(cells: Array2<T>) -> Self {
+        Self { cells }
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): Pos) -> &Self::Output {
+        &self.cells[(y, x)]
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, (x, y): Pos) -> &mut Self::Output {
+        &mut self.cells[(y, x)]
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_digits_indexes_by_x_then_y() {
+        let grid = parse_digits(&["123", "456"]).unwrap();
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 0)], 3);
+        assert_eq!(grid[(0, 1)], 4);
+        assert_eq!(grid.to_string(), "123\n456");
+    }
+
+    #[test]
+    fn parse_digits_rejects_ragged_rows() {
+        let error = parse_digits(&["123", "45"]).unwrap_err();
+
+        assert_eq!((error.line, error.column), (2, 1));
+
+        let error = parse_digits(&["123", "4x6"]).unwrap_err();
+
+        assert_eq!((error.line, error.column), (2, 2));
+    }
+
+    #[test]
+    fn parsers_reject_empty_grids() {
+        let no_lines: [&str; 0] = [];
+
+        for lines in [&no_lines[..], &[""], &["", "123"]] {
+            let error = parse_digits(lines).unwrap_err();
+            assert_eq!((error.line, error.column), (1, 1));
+
+            let error = parse_cells(parse::lines(lines), "a dot", |c| (c == '.').then_some(()))
+                .unwrap_err();
+            assert_eq!((error.line, error.column), (1, 1));
+        }
+
+        let error = parse_digits(&["123", ""]).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+    }
+
+    #[test]
+    fn neighbors_stay_in_bounds() {
+        let grid = Grid::from_elem(3, 2, 0);
+
+        assert_eq!(grid.neighbors4((0, 0)).collect_vec(), [(1, 0), (0, 1)]);
+        assert_eq!(grid.neighbors8((1, 1)).count(), 5);
+        assert_eq!(grid.neighbors((2, 1), Connectivity::Eight).count(), 3);
+    }
+
+    #[test]
+    fn flood_fill_stops_at_walls() {
+        let mut grid = parse_digits(&["0090", "0090", "9900"]).unwrap();
+
+        grid.flood_fill([(0, 0)], Connectivity::Four, |_, cell| {
+            let open = *cell == 0;
+            if open {
+                *cell = 1;
+            }
+
+            open
+        });
+
+        assert_eq!(grid.to_string(), "1190\n1190\n9900");
+    }
+}
@@ -0,0 +1,268 @@
+//! Puzzle input discovery and reading.
+
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
+
+use clap::{Arg, Command};
+
+use crate::params::{Kind, Param, Params};
+use crate::parse::ParseError;
+use crate::report::{self, Format};
+
+/// Environment variable overriding the directory searched for default inputs.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+const DEFAULT_INPUT_DIR: &str = "input";
+const STDIN_PATH: &str = "-";
+
+/// Buffered puzzle input, read either from a file or from stdin.
+pub struct Input {
+    reader: Box<dyn BufRead>,
+}
+
+/// Returns the file name of a day's input, e.g. `day07.txt`.
+pub fn file_name(day: u32) -> String {
+    format!("day{:02}.txt", day)
+}
+
+/// Returns the path a day's input is looked up at when none is given,
+/// i.e. `input/dayNN.txt` or `$AOC_INPUT_DIR/dayNN.txt`.
+pub fn default_path(day: u32) -> PathBuf {
+    let dir = std::env::var_os(INPUT_DIR_VAR)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(DEFAULT_INPUT_DIR));
+
+    dir.join(file_name(day))
+}
+
+impl Input {
+    /// Parses the command line of a solution and opens the input it names.
+    ///
+    /// Without an `INPUT_FILE` argument the default input of `day` is used,
+    /// while `-` reads from stdin.
+    pub fn parse_args(day: u32, description: &str) -> io::Result<Self> {
+        Self::parse_args_with(day, description, &[]).map(|(input, _)| input)
+    }
+
+    /// Like [`Input::parse_args`], additionally accepting `--param key=value` overrides,
+    /// `--<switch>` flags and `--<option> <VALUE>` options of the `declared` puzzle parameters.
+    ///
+    /// Both also register the run for [`report::measure_and_print`],
+    /// which solves the parts chosen with `--part` and prints in the format chosen with `--format`.
+    pub fn parse_args_with(
+        day: u32,
+        description: &str,
+        declared: &[Param],
+    ) -> io::Result<(Self, Params)> {
+        let (values, flags): (Vec<_>, Vec<_>) =
+            declared.iter().partition(|p| p.kind() == Kind::Value);
+
+        let listing = values.iter().map(|p| format!("    {}", p.describe()));
+        let after_help = format!("PARAMETERS:\n{}", listing.collect::<Vec<_>>().join("\n"));
+
+        let bin_name = bin_name();
+
+        let mut command = Command::new(&bin_name)
+            .about(description)
+            .arg(
+                Arg::new("INPUT_FILE")
+                    .help("Puzzle input, `-` for stdin [default: input/dayNN.txt]"),
+            )
+            .arg(
+                Arg::new("PART")
+                    .long("part")
+                    .takes_value(true)
+                    .possible_values(["1", "2"])
+                    .help("Only solve the given part [default: both]"),
+            )
+            .arg(
+                Arg::new("FORMAT")
+                    .long("format")
+                    .takes_value(true)
+                    .possible_values(Format::NAMES)
+                    .default_value("human")
+                    .help("Output format of the answer and timings"),
+            );
+
+        for flag in &flags {
+            let arg = Arg::new(flag.name()).long(flag.name()).help(flag.help());
+
+            command = command.arg(match flag.kind() {
+                Kind::Option { value_name } => arg
+                    .takes_value(true)
+                    .value_name(value_name)
+                    .allow_hyphen_values(true),
+                _ => arg,
+            });
+        }
+
+        if !values.is_empty() {
+            command = command
+                .arg(
+                    Arg::new("PARAM")
+                        .long("param")
+                        .value_name("KEY=VALUE")
+                        .takes_value(true)
+                        .multiple_occurrences(true)
+                        .help("Overrides a puzzle parameter, see below"),
+                )
+                .after_help(after_help.as_str());
+        }
+
+        let matches = command.get_matches_mut();
+
+        let assignments = match values.as_slice() {
+            [] => None,
+            _ => matches.values_of("PARAM"),
+        };
+        let assignments = assignments.into_iter().flatten();
+        let mut params = Params::resolve(declared, assignments).map_err(invalid_value)?;
+
+        for flag in flags {
+            match (flag.kind(), matches.value_of(flag.name())) {
+                (Kind::Option { .. }, Some(value)) => {
+                    params.set_option(flag, value).map_err(invalid_value)?
+                }
+                (Kind::Switch, _) if matches.is_present(flag.name()) => params.enable(flag.name()),
+                _ => {}
+            }
+        }
+
+        let path = matches.value_of("INPUT_FILE").map(Path::new);
+        let input_path = path.map_or_else(|| default_path(day), Path::to_path_buf);
+        let part = matches.value_of("PART").and_then(|p| p.parse().ok());
+        let format = matches.value_of_t("FORMAT").unwrap_or_else(|e| e.exit());
+        report::start(day, part, input_path.display().to_string(), format);
+
+        let input = Self::open(day, path)?;
+
+        Ok((input, params))
+    }
+
+    /// Opens the given input path, falling back to the default input of `day`.
+    pub fn open(day: u32, path: Option<&Path>) -> io::Result<Self> {
+        match path {
+            Some(p) if p == Path::new(STDIN_PATH) => Ok(Self::stdin()),
+            Some(p) => Self::from_file(p),
+            None => {
+                let path = default_path(day);
+
+                Self::from_file(&path).map_err(|e| match e.kind() {
+                    io::ErrorKind::NotFound => {
+                        let msg = format!(
+                            "No input file given and {} does not exist (pass a path or set {})",
+                            path.display(),
+                            INPUT_DIR_VAR
+                        );
+
+                        io::Error::new(io::ErrorKind::NotFound, msg)
+                    }
+                    _ => e,
+                })
+            }
+        }
+    }
+
+    /// Reads input from stdin.
+    pub fn stdin() -> Self {
+        Self {
+            reader: Box::new(BufReader::new(io::stdin())),
+        }
+    }
+
+    /// Reads input from a file.
+    pub fn from_file(path: &Path) -> io::Result<Self> {
+        let file = File::open(path).map_err(|e| {
+            let msg = format!("Failed to open {}: {}", path.display(), e);
+
+            io::Error::new(e.kind(), msg)
+        })?;
+
+        Ok(Self {
+            reader: Box::new(BufReader::new(file)),
+        })
+    }
+
+    /// Reads all input lines.
+    pub fn read_lines(self) -> io::Result<Vec<String>> {
+        self.lines().collect()
+    }
+}
+
+impl Read for Input {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.reader.read(buf)
+    }
+}
+
+impl BufRead for Input {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        self.reader.fill_buf()
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.reader.consume(amt)
+    }
+}
+
+/// Wraps a malformed parameter value into the error returned by [`Input::parse_args_with`].
+fn invalid_value(error: ParseError) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, error)
+}
+
+fn bin_name() -> String {
+    std::env::args_os()
+        .next()
+        .as_deref()
+        .map(Path::new)
+        .and_then(Path::file_stem)
+        .map(|s| s.to_string_lossy().into_owned())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+
+    #[test]
+    fn missing_files_are_named() {
+        let path = Path::new("no/such/day99.txt");
+        let error = Input::open(99, Some(path)).err().unwrap();
+
+        assert_eq!(error.kind(), io::ErrorKind::NotFound);
+        assert!(error
+            .to_string()
+            .starts_with("Failed to open no/such/day99.txt: "));
+    }
+
+    // the only test touching the environment variable, so that tests don't race on it
+    #[test]
+    fn default_inputs_are_looked_up_in_the_input_dir() {
+        let dir = std::env::temp_dir().join(format!("aoc-input-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("day07.txt"), "16,1,2\n0,4\n").unwrap();
+
+        std::env::set_var(INPUT_DIR_VAR, &dir);
+        let default = default_path(7);
+        let found = Input::open(7, None).and_then(Input::read_lines);
+        let missing = Input::open(8, None).err().unwrap();
+        std::env::remove_var(INPUT_DIR_VAR);
+
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(default, dir.join("day07.txt"));
+        assert_eq!(found.unwrap(), ["16,1,2", "0,4"]);
+        assert_eq!(missing.kind(), io::ErrorKind::NotFound);
+        assert_eq!(
+            missing.to_string(),
+            format!(
+                "No input file given and {} does not exist (pass a path or set AOC_INPUT_DIR)",
+                dir.join("day08.txt").display()
+            )
+        );
+        assert_eq!(default_path(7), Path::new("input/day07.txt"));
+    }
+}
@@ -1 +1,15 @@
 pub mod bits;
+pub mod gen;
+pub mod geom3;
+pub mod grid;
+pub mod input;
+pub mod ocr;
+pub mod params;
+pub mod parse;
+pub mod perf;
+pub mod regions;
+pub mod report;
+pub mod search;
+pub mod snailfish;
+pub mod submarine;
+pub mod windows;
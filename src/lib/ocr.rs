@@ -0,0 +1,218 @@
+//! Recognition of the letters puzzles draw with lit pixels.
+//!
+//! Letters use the usual 4x6 font, laid out left to right with
+//! one blank column between them. Letters drawn 5 pixels wide, like `Y`,
+//! don't fit that layout and are not recognized.
+
+use std::error::Error;
+use std::fmt::{self, Display};
+
+use crate::grid::Grid;
+
+pub const GLYPH_WIDTH: usize = 4;
+pub const GLYPH_HEIGHT: usize = 6;
+
+/// Horizontal distance between the starts of two consecutive glyphs.
+const GLYPH_PITCH: usize = GLYPH_WIDTH + 1;
+
+/// Known glyphs, rows concatenated top to bottom.
+pub(crate) const FONT: [(char, &str); 17] = [
+    ('A', ".##.#..##..######..##..#"),
+    ('B', "###.#..####.#..##..####."),
+    ('C', ".##.#..##...#...#..#.##."),
+    ('E', "#####...###.#...#...####"),
+    ('F', "#####...###.#...#...#..."),
+    ('G', ".##.#..##...#.###..#.###"),
+    ('H', "#..##..######..##..##..#"),
+    ('I', ".###..#...#...#...#..###"),
+    ('J', "..##...#...#...##..#.##."),
+    ('K', "#..##.#.##..#.#.#.#.#..#"),
+    ('L', "#...#...#...#...#...####"),
+    ('O', ".##.#..##..##..##..#.##."),
+    ('P', "###.#..##..####.#...#..."),
+    ('R', "###.#..##..####.#.#.#..#"),
+    ('S', ".####...#....##....####."),
+    ('U', "#..##..##..##..##..#.##."),
+    ('Z', "####...#..#..#..#...####"),
+];
+
+/// Why a rendering could not be read.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum OcrError {
+    /// The rendering is not exactly one glyph tall.
+    Height(usize),
+    /// The glyph at `index` (counting from 0), starting at column `x`, is not in the font.
+    UnknownGlyph {
+        index: usize,
+        x: usize,
+        glyph: String,
+    },
+}
+
+/// Reads the letters drawn by the `true` pixels of `pixels`.
+pub fn recognize(pixels: &Grid<bool>) -> Result<String, OcrError> {
+    if pixels.height() != GLYPH_HEIGHT {
+        return Err(OcrError::Height(pixels.height()));
+    }
+
+    // the rightmost glyph's last column may be blank and cut off
+    let n_glyphs = pixels.width().div_ceil(GLYPH_PITCH);
+
+    (0..n_glyphs)
+        .map(|index| {
+            let x = index * GLYPH_PITCH;
+            let glyph = glyph_at(pixels, x);
+
+            FONT.iter()
+                .find(|(_, pattern)| *pattern == glyph)
+                .map(|&(letter, _)| letter)
+                .ok_or(OcrError::UnknownGlyph { index, x, glyph })
+        })
+        .collect()
+}
+
+/// Draws lit pixels as `#` and the rest as spaces, one line per row.
+pub fn render(pixels: &Grid<bool>) -> String {
+    pixels.map(|&lit| if lit { '#' } else { ' ' }).to_string()
+}
+
+fn glyph_at(pixels: &Grid<bool>, x0: usize) -> String {
+    let mut glyph = String::with_capacity(GLYPH_WIDTH * GLYPH_HEIGHT);
+
+    for y in 0..GLYPH_HEIGHT {
+        for x in x0..x0 + GLYPH_WIDTH {
+            let lit = pixels.get((x, y)).copied().unwrap_or(false);
+
+            glyph.push(if lit { '#' } else { '.' });
+        }
+    }
+
+    glyph
+}
+
+impl Display for OcrError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Height(height) => write!(
+                f,
+                "Expected letters {} pixels tall, found {} rows",
+                GLYPH_HEIGHT, height
+            ),
+            Self::UnknownGlyph { index, x, glyph } => {
+                writeln!(
+                    f,
+                    "Unknown glyph #{} at columns {}..{}:",
+                    index + 1,
+                    x,
+                    x + GLYPH_WIDTH
+                )?;
+
+                let rows = glyph.as_bytes().chunks(GLYPH_WIDTH);
+                let rows = rows.map(|row| String::from_utf8_lossy(row).into_owned());
+
+                write!(f, "{}", rows.collect::<Vec<_>>().join("\n"))
+            }
+        }
+    }
+}
+
+impl Error for OcrError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Lays out the glyphs of `code` the way the puzzles do.
+    fn draw(code: &str) -> Grid<bool> {
+        let width = code.len() * GLYPH_PITCH;
+        let mut pixels = Grid::from_elem(width, GLYPH_HEIGHT, false);
+
+        for (i, letter) in code.chars().enumerate() {
+            let &(_, glyph) = FONT.iter().find(|&&(l, _)| l == letter).unwrap();
+            let lit = glyph.bytes().enumerate().filter(|&(_, b)| b == b'#');
+
+            for (j, _) in lit {
+                pixels[(i * GLYPH_PITCH + j % GLYPH_WIDTH, j / GLYPH_WIDTH)] = true;
+            }
+        }
+
+        pixels
+    }
+
+    #[test]
+    fn recognizes_every_glyph() {
+        for &(letter, _) in &FONT {
+            assert_eq!(
+                recognize(&draw(&letter.to_string())),
+                Ok(letter.to_string())
+            );
+        }
+    }
+
+    #[test]
+    fn round_trips_codes() {
+        let code: String = FONT.iter().map(|&(letter, _)| letter).collect();
+
+        assert_eq!(recognize(&draw(&code)), Ok(code));
+        assert_eq!(recognize(&draw("IJZ")), Ok("IJZ".to_string()));
+    }
+
+    #[test]
+    fn keeps_a_blank_first_column() {
+        // `I` leaves column 0 blank, which must not shift the glyphs after it
+        let pixels = draw("ICU");
+
+        assert!(pixels.column(0).iter().all(|&lit| !lit));
+        assert_eq!(recognize(&pixels), Ok("ICU".to_string()));
+    }
+
+    #[test]
+    fn reads_codes_without_the_trailing_gap() {
+        // drawn up to the last lit pixel, the gap after the last glyph is missing
+        let pixels = draw("JC");
+        let width = 2 * GLYPH_PITCH - 1;
+        let cells = pixels
+            .rows()
+            .flat_map(|row| row.iter().take(width).copied().collect::<Vec<_>>())
+            .collect();
+
+        assert_eq!(
+            recognize(&Grid::from_shape_vec(width, GLYPH_HEIGHT, cells)),
+            Ok("JC".to_string())
+        );
+    }
+
+    #[test]
+    fn reports_unknown_glyphs() {
+        let mut pixels = draw("AB");
+        pixels[(GLYPH_PITCH, 0)] = false;
+
+        let error = recognize(&pixels).unwrap_err();
+        assert_eq!(
+            error,
+            OcrError::UnknownGlyph {
+                index: 1,
+                x: GLYPH_PITCH,
+                glyph: ".##.#..####.#..##..####.".to_string(),
+            }
+        );
+        assert!(error
+            .to_string()
+            .starts_with("Unknown glyph #2 at columns 5..9:\n.##."));
+    }
+
+    #[test]
+    fn rejects_other_heights() {
+        let pixels = Grid::from_elem(GLYPH_WIDTH, GLYPH_HEIGHT + 1, false);
+
+        assert_eq!(recognize(&pixels), Err(OcrError::Height(GLYPH_HEIGHT + 1)));
+    }
+
+    #[test]
+    fn renders_lit_pixels() {
+        let rendered = render(&draw("L"));
+
+        assert_eq!(rendered.lines().next(), Some("#    "));
+        assert_eq!(rendered.lines().last(), Some("#### "));
+    }
+}
@@ -0,0 +1,343 @@
+//! Puzzle parameters that can be overridden from the command line.
+//!
+//! Solutions declare their tunable constants (simulation lengths, bounds etc.)
+//! as [`Param`]s with the puzzle's values as defaults, which are then
+//! overridden with `--param key=value`. Boolean switches are passed as `--<name>`,
+//! and options without a default as `--<name> <VALUE>`.
+//!
+//! Values are parsed into their declared types as soon as they are given,
+//! with malformed ones reported as [`ParseError`]s.
+
+use std::any::Any;
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::rc::Rc;
+use std::str::FromStr;
+
+use crate::parse::{Line, ParseError};
+
+/// A parameter value of its declared type, along with the name of that type.
+#[derive(Clone, Debug)]
+struct Value {
+    value: Rc<dyn Any>,
+    type_name: &'static str,
+}
+
+/// Declaration of a tunable puzzle constant.
+#[derive(Clone, Debug)]
+pub struct Param {
+    name: &'static str,
+    default: String,
+    help: &'static str,
+    type_name: &'static str,
+    parse: fn(&str) -> Option<Value>,
+    /// The smallest accepted value, if any.
+    min: Option<String>,
+    /// Checks whether a value is at least the minimum, comparing them as the declared type.
+    reaches: fn(&str, &str) -> bool,
+    kind: Kind,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Kind {
+    /// Set with `--param name=value`, with a default.
+    Value,
+    /// Turned on with `--name`.
+    Switch,
+    /// Set with `--name <VALUE>`, absent otherwise.
+    Option { value_name: &'static str },
+}
+
+/// Parameter values of a single run, with defaults filled in.
+#[derive(Clone, Debug, Default)]
+pub struct Params {
+    values: HashMap<&'static str, Value>,
+}
+
+impl Param {
+    /// Declares a parameter of type `T`.
+    pub fn new<T: FromStr + Display + PartialOrd + 'static>(
+        name: &'static str,
+        default: T,
+        help: &'static str,
+    ) -> Self {
+        Self {
+            name,
+            default: default.to_string(),
+            help,
+            type_name: short_type_name::<T>(),
+            parse: parse_value::<T>,
+            min: None,
+            reaches: reaches::<T>,
+            kind: Kind::Value,
+        }
+    }
+
+    /// Rejects values below `min`, compared as the declared type.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min` does not parse as the declared type.
+    pub fn at_least<M: Display>(self, min: M) -> Self {
+        let min = min.to_string();
+
+        assert!(
+            (self.parse)(&min).is_some(),
+            "Minimum {} of parameter {} is not a {}",
+            min,
+            self.name,
+            self.type_name
+        );
+
+        Self {
+            min: Some(min),
+            ..self
+        }
+    }
+
+    /// Declares a boolean switch, off unless `--<name>` is given.
+    pub fn switch(name: &'static str, help: &'static str) -> Self {
+        Self {
+            kind: Kind::Switch,
+            ..Self::new(name, false, help)
+        }
+    }
+
+    /// Declares an option of type `T` without a default, set with `--<name> <VALUE>`.
+    pub fn option<T: FromStr + PartialOrd + 'static>(
+        name: &'static str,
+        value_name: &'static str,
+        help: &'static str,
+    ) -> Self {
+        Self {
+            name,
+            default: String::new(),
+            help,
+            type_name: short_type_name::<T>(),
+            parse: parse_value::<T>,
+            min: None,
+            reaches: reaches::<T>,
+            kind: Kind::Option { value_name },
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    pub fn help(&self) -> &'static str {
+        self.help
+    }
+
+    pub(crate) fn kind(&self) -> Kind {
+        self.kind
+    }
+
+    /// One line summary for usage messages, e.g. `days=80 (usize)  Number of days to simulate`.
+    pub fn describe(&self) -> String {
+        let min = match &self.min {
+            Some(min) => format!(", at least {}", min),
+            None => String::new(),
+        };
+
+        format!(
+            "{}={} ({}{})  {}",
+            self.name, self.default, self.type_name, min, self.help
+        )
+    }
+
+    /// Parses `value` as the declared type, failing with what was expected instead
+    /// if it does not parse or is below the minimum.
+    fn check(&self, value: &str) -> Result<Value, String> {
+        let parsed = (self.parse)(value).ok_or(self.type_name)?;
+
+        match &self.min {
+            Some(min) if !(self.reaches)(value, min) => Err(format!("at least {}", min)),
+            _ => Ok(parsed),
+        }
+    }
+
+    fn default_value(&self) -> Value {
+        (self.parse)(&self.default).expect("Defaults are of the declared type")
+    }
+}
+
+impl Params {
+    /// Resolves `key=value` assignments against the declared parameters.
+    ///
+    /// Fails on unknown keys, malformed assignments and values
+    /// that do not parse as the declared type or are below its minimum.
+    /// Errors count the assignments as lines, starting from 1.
+    pub fn resolve<'a, I>(declared: &[Param], assignments: I) -> Result<Self, ParseError>
+    where
+        I: IntoIterator<Item = &'a str>,
+    {
+        let mut values: HashMap<_, _> = declared
+            .iter()
+            .filter(|p| !matches!(p.kind, Kind::Option { .. }))
+            .map(|p| (p.name, p.default_value()))
+            .collect();
+
+        for (i, assignment) in assignments.into_iter().enumerate() {
+            let line = Line::new(i + 1, assignment);
+            let mut cursor = line.cursor();
+            let key = cursor.until("=")?;
+            let value = cursor.rest();
+
+            let settable = declared.iter().filter(|p| p.kind == Kind::Value);
+
+            let param = settable.clone().find(|p| p.name == key).ok_or_else(|| {
+                let known = settable.map(Param::name).collect::<Vec<_>>();
+
+                line.error(key, format!("a parameter, one of: {}", known.join(", ")))
+            })?;
+
+            let parsed = param.check(value).map_err(|expected| {
+                line.error(value, format!("{} for parameter {}", expected, key))
+            })?;
+
+            values.insert(param.name, parsed);
+        }
+
+        Ok(Self { values })
+    }
+
+    /// Turns on a declared switch.
+    pub(crate) fn enable(&mut self, name: &'static str) {
+        self.values.insert(name, Value::new(true));
+    }
+
+    /// Sets a declared option, failing if the value does not parse as its type
+    /// or is below its minimum.
+    pub(crate) fn set_option(&mut self, param: &Param, value: &str) -> Result<(), ParseError> {
+        let parsed = param.check(value).map_err(|expected| {
+            Line::new(1, value).error(value, format!("{} for --{}", expected, param.name))
+        })?;
+
+        self.values.insert(param.name, parsed);
+
+        Ok(())
+    }
+
+    /// Returns the value of a declared parameter.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `name` was not declared or was declared with a different type.
+    /// Options have no default, use [`Params::get_option`] for them.
+    pub fn get<T: Clone + 'static>(&self, name: &str) -> T {
+        let value = self
+            .values
+            .get(name)
+            .unwrap_or_else(|| panic!("Parameter {} was not declared", name));
+
+        downcast(name, value)
+    }
+
+    /// Returns the value of a declared option, if it was given.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the option was declared with a different type.
+    pub fn get_option<T: Clone + 'static>(&self, name: &str) -> Option<T> {
+        let value = self.values.get(name)?;
+
+        Some(downcast(name, value))
+    }
+}
+
+impl Value {
+    fn new<T: 'static>(value: T) -> Self {
+        Self {
+            value: Rc::new(value),
+            type_name: short_type_name::<T>(),
+        }
+    }
+}
+
+fn parse_value<T: FromStr + 'static>(value: &str) -> Option<Value> {
+    let parsed = value.parse::<T>().ok()?;
+
+    Some(Value::new(parsed))
+}
+
+fn reaches<T: FromStr + PartialOrd>(value: &str, min: &str) -> bool {
+    match (value.parse::<T>(), min.parse::<T>()) {
+        (Ok(value), Ok(min)) => value >= min,
+        _ => false,
+    }
+}
+
+fn downcast<T: Clone + 'static>(name: &str, value: &Value) -> T {
+    value.value.downcast_ref::<T>().cloned().unwrap_or_else(|| {
+        panic!(
+            "Parameter {} is declared as {} but read as {}",
+            name,
+            value.type_name,
+            short_type_name::<T>()
+        )
+    })
+}
+
+fn short_type_name<T>() -> &'static str {
+    let type_name = std::any::type_name::<T>();
+
+    type_name.rsplit("::").next().unwrap_or(type_name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn resolve(param: Param, value: &str) -> Result<Params, ParseError> {
+        let assignment = format!("{}={}", param.name(), value);
+
+        Params::resolve(&[param], [assignment.as_str()])
+    }
+
+    #[test]
+    fn minimums_compare_as_the_declared_type() {
+        let bound = || Param::new::<i64>("bound", 50, "").at_least(0);
+
+        assert_eq!(
+            resolve(bound(), "3000000000").unwrap().get::<i64>("bound"),
+            3000000000
+        );
+        assert_eq!(
+            resolve(bound(), "-1").unwrap_err().expected,
+            "at least 0 for parameter bound"
+        );
+
+        let die = || Param::new::<u32>("die", 100, "").at_least(1);
+
+        assert_eq!(
+            resolve(die(), "4000000000").unwrap().get::<u32>("die"),
+            4000000000
+        );
+        assert!(resolve(die(), "0").is_err());
+    }
+
+    #[test]
+    fn rejects_values_of_other_types() {
+        let error = resolve(Param::new::<u32>("die", 100, ""), "-1").unwrap_err();
+
+        assert_eq!(
+            (error.column, error.expected.as_str()),
+            (5, "u32 for parameter die")
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Minimum -1 of parameter die is not a u32")]
+    fn minimums_must_be_of_the_declared_type() {
+        let _ = Param::new::<u32>("die", 100, "").at_least(-1);
+    }
+
+    #[test]
+    #[should_panic(expected = "Parameter score is declared as u32 but read as u64")]
+    fn reading_another_type_names_both() {
+        let params = Params::resolve(&[Param::new::<u32>("score", 21, "")], []).unwrap();
+
+        params.get::<u64>("score");
+    }
+}
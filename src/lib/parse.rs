@@ -0,0 +1,442 @@
+//! Puzzle input parsing helpers.
+//!
+//! Lines are consumed piece by piece with a [`Cursor`], whose combinators
+//! borrow their output from the input and report failures at the exact column.
+//! Grids are read by [`crate::grid::parse_digits`] and [`crate::grid::parse_cells`].
+
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+use itertools::Itertools;
+
+/// Describes where and why parsing the puzzle input failed.
+///
+/// Both `line` and `column` count from 1.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+    pub found: String,
+}
+
+/// A single line of puzzle input, remembering its position for error reporting.
+#[derive(Clone, Copy, Debug)]
+pub struct Line<'a> {
+    number: usize,
+    text: &'a str,
+}
+
+/// The unconsumed remainder of a line.
+#[derive(Clone, Copy, Debug)]
+pub struct Cursor<'a> {
+    line: Line<'a>,
+    rest: &'a str,
+}
+
+/// Numbers a sequence of input lines, starting from 1.
+///
+/// A trailing `\r` left over from CRLF line endings is dropped.
+pub fn lines<S: AsRef<str>>(lines: &[S]) -> impl Iterator<Item = Line<'_>> {
+    lines.iter().enumerate().map(|(i, l)| {
+        let text = l.as_ref();
+
+        Line::new(i + 1, text.strip_suffix('\r').unwrap_or(text))
+    })
+}
+
+/// Returns the first line of input, failing with `expected` if there is none.
+pub fn first_line<'a, S: AsRef<str>>(
+    lines: &'a [S],
+    expected: &str,
+) -> Result<Line<'a>, ParseError> {
+    self::lines(lines)
+        .next()
+        .ok_or_else(|| ParseError::end_of_input(1, expected))
+}
+
+/// Splits input into sections separated by one or more blank lines.
+pub fn sections<S: AsRef<str>>(lines: &[S]) -> impl Iterator<Item = Vec<Line<'_>>> {
+    let mut lines = self::lines(lines).peekable();
+
+    std::iter::from_fn(move || {
+        while lines.next_if(|l| l.text().is_empty()).is_some() {}
+
+        let section = lines
+            .by_ref()
+            .take_while(|l| !l.text().is_empty())
+            .collect_vec();
+
+        (!section.is_empty()).then_some(section)
+    })
+}
+
+impl ParseError {
+    pub fn new<E: Into<String>, F: Into<String>>(
+        line: usize,
+        column: usize,
+        expected: E,
+        found: F,
+    ) -> Self {
+        Self {
+            line,
+            column,
+            expected: expected.into(),
+            found: found.into(),
+        }
+    }
+
+    /// Creates an error for input that ended before `expected` was found.
+    pub fn end_of_input<E: Into<String>>(line: usize, expected: E) -> Self {
+        Self::new(line, 1, expected, "end of input")
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Parse error at line {}, column {}: expected {}, found {:?}",
+            self.line, self.column, self.expected, self.found
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+impl<'a> Line<'a> {
+    pub fn new(number: usize, text: &'a str) -> Self {
+        Self { number, text }
+    }
+
+    /// Line number, counting from 1.
+    pub fn number(&self) -> usize {
+        self.number
+    }
+
+    pub fn text(&self) -> &'a str {
+        self.text
+    }
+
+    /// Starts consuming the line from its beginning.
+    pub fn cursor(&self) -> Cursor<'a> {
+        Cursor {
+            line: *self,
+            rest: self.text,
+        }
+    }
+
+    /// Returns the column (counting from 1) at which `token` starts.
+    ///
+    /// Tokens borrowed from the line are located exactly. Any other token is looked up
+    /// by its text, falling back to the start of the line if the line doesn't contain it.
+    pub fn column_of(&self, token: &str) -> usize {
+        let start = self.text.as_ptr() as usize;
+        let offset = (token.as_ptr() as usize)
+            .checked_sub(start)
+            .filter(|&off| off + token.len() <= self.text.len())
+            .filter(|&off| self.text.is_char_boundary(off))
+            .or_else(|| self.text.find(token))
+            .unwrap_or(0);
+
+        self.text[..offset].chars().count() + 1
+    }
+
+    /// Creates an error pointing at `token`, preferably a subslice of this line.
+    pub fn error<E: Into<String>>(&self, token: &str, expected: E) -> ParseError {
+        let found = if token.is_empty() {
+            "end of line"
+        } else {
+            token
+        };
+
+        ParseError::new(self.number, self.column_of(token), expected, found)
+    }
+
+    /// Creates an error for a line that does not match the expected format at all.
+    pub fn mismatch<E: Into<String>>(&self, expected: E) -> ParseError {
+        ParseError::new(self.number, 1, expected, self.text)
+    }
+
+    /// Parses `token`, reporting failures at its column in this line.
+    pub fn parse<T: FromStr>(&self, token: &str) -> Result<T, ParseError> {
+        token.parse().map_err(|_| {
+            let type_name = std::any::type_name::<T>();
+            let short_name = type_name.rsplit("::").next().unwrap_or(type_name);
+
+            self.error(token, short_name)
+        })
+    }
+
+    /// Parses a list of values separated by `sep`.
+    pub fn parse_list<T: FromStr>(&self, sep: &str) -> Result<Vec<T>, ParseError> {
+        self.text
+            .split(sep)
+            .map(|token| self.parse(token))
+            .collect()
+    }
+
+    /// Parses every character of the line as a digit in the given `radix`.
+    pub fn digits(&self, radix: u32) -> Result<Vec<u32>, ParseError> {
+        self.text
+            .char_indices()
+            .map(|(i, c)| {
+                c.to_digit(radix).ok_or_else(|| {
+                    let token = &self.text[i..i + c.len_utf8()];
+
+                    self.error(token, format!("a base {} digit", radix))
+                })
+            })
+            .collect()
+    }
+}
+
+impl<'a> Cursor<'a> {
+    pub fn line(&self) -> Line<'a> {
+        self.line
+    }
+
+    /// Returns the unconsumed part of the line.
+    pub fn rest(&self) -> &'a str {
+        self.rest
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rest.is_empty()
+    }
+
+    /// Consumes `literal`.
+    pub fn tag(&mut self, literal: &str) -> Result<(), ParseError> {
+        match self.rest.strip_prefix(literal) {
+            Some(rest) => {
+                self.rest = rest;
+                Ok(())
+            }
+            None => {
+                let found = self.prefix(literal.chars().count());
+
+                Err(self.line.error(found, format!("`{}`", literal)))
+            }
+        }
+    }
+
+    /// Consumes the longest non-empty run of characters satisfying `pred`.
+    pub fn take_while<E, P>(&mut self, expected: E, pred: P) -> Result<&'a str, ParseError>
+    where
+        E: Into<String>,
+        P: Fn(char) -> bool,
+    {
+        let end = self.rest.find(|c| !pred(c)).unwrap_or(self.rest.len());
+
+        if end == 0 {
+            return Err(self.line.error(self.word_ahead(), expected));
+        }
+
+        Ok(self.advance(end))
+    }
+
+    /// Consumes a run of alphanumeric characters.
+    pub fn word(&mut self) -> Result<&'a str, ParseError> {
+        self.take_while("a word", char::is_alphanumeric)
+    }
+
+    /// Consumes everything up to and including `delim`, returning the part before it.
+    pub fn until(&mut self, delim: &str) -> Result<&'a str, ParseError> {
+        match self.rest.find(delim) {
+            Some(end) => {
+                let token = self.advance(end);
+                self.rest = &self.rest[delim.len()..];
+
+                Ok(token)
+            }
+            None => {
+                let eol = &self.rest[self.rest.len()..];
+
+                Err(self.line.error(eol, format!("`{}`", delim)))
+            }
+        }
+    }
+
+    /// Consumes an optionally signed decimal integer.
+    pub fn integer<T: FromStr>(&mut self) -> Result<T, ParseError> {
+        let sign = self.rest.strip_prefix(['-', '+']).map_or(0, |_| 1);
+        let digits = self.rest[sign..]
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(self.rest.len() - sign);
+
+        if digits == 0 {
+            return Err(self.line.error(self.word_ahead(), "an integer"));
+        }
+
+        let token = self.advance(sign + digits);
+
+        self.line.parse(token)
+    }
+
+    /// Consumes an inclusive range of integers written as `<start>..<end>`.
+    pub fn range<T: FromStr>(&mut self) -> Result<RangeInclusive<T>, ParseError> {
+        let start = self.integer()?;
+        self.tag("..")?;
+        let end = self.integer()?;
+
+        Ok(start..=end)
+    }
+
+    /// Consumes a range like [`Cursor::range`], rejecting one that ends below its start.
+    pub fn ascending_range<T>(&mut self) -> Result<RangeInclusive<T>, ParseError>
+    where
+        T: FromStr + PartialOrd,
+    {
+        let (range, token) = self.consumed(|c| c.range::<T>())?;
+
+        if range.is_empty() {
+            return Err(self.line.error(token, "an ascending range"));
+        }
+
+        Ok(range)
+    }
+
+    /// Consumes one or more items separated by `sep`.
+    pub fn separated<T, F>(&mut self, sep: &str, mut item: F) -> Result<Vec<T>, ParseError>
+    where
+        F: FnMut(&mut Self) -> Result<T, ParseError>,
+    {
+        let mut items = vec![item(self)?];
+
+        while let Some(rest) = self.rest.strip_prefix(sep) {
+            self.rest = rest;
+            items.push(item(self)?);
+        }
+
+        Ok(items)
+    }
+
+    /// Runs `parser`, also returning the part of the line it consumed.
+    pub fn consumed<T, F>(&mut self, parser: F) -> Result<(T, &'a str), ParseError>
+    where
+        F: FnOnce(&mut Self) -> Result<T, ParseError>,
+    {
+        let start = self.rest;
+        let value = parser(self)?;
+
+        Ok((value, &start[..start.len() - self.rest.len()]))
+    }
+
+    /// Checks that the whole line has been consumed.
+    pub fn end(self) -> Result<(), ParseError> {
+        if self.rest.is_empty() {
+            Ok(())
+        } else {
+            Err(self.line.error(self.rest, "end of line"))
+        }
+    }
+
+    fn advance(&mut self, len: usize) -> &'a str {
+        let (token, rest) = self.rest.split_at(len);
+        self.rest = rest;
+
+        token
+    }
+
+    /// The next `n` characters, for reporting what was found instead of a literal.
+    fn prefix(&self, n: usize) -> &'a str {
+        let end = self
+            .rest
+            .char_indices()
+            .nth(n)
+            .map_or(self.rest.len(), |(i, _)| i);
+
+        &self.rest[..end]
+    }
+
+    /// The next whitespace-delimited word, for reporting what was found instead of a token.
+    fn word_ahead(&self) -> &'a str {
+        let end = self
+            .rest
+            .char_indices()
+            .skip(1)
+            .find(|(_, c)| c.is_whitespace())
+            .map_or(self.rest.len(), |(i, _)| i);
+
+        &self.rest[..end]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cursor_reports_columns() {
+        let line = Line::new(3, "move 12 from x");
+        let mut cursor = line.cursor();
+
+        assert_eq!(cursor.word(), Ok("move"));
+        cursor.tag(" ").unwrap();
+        assert_eq!(cursor.integer::<u32>(), Ok(12));
+
+        let error = cursor.tag(" to ").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 8));
+        assert_eq!(error.expected, "` to `");
+        assert_eq!(error.found, " fro");
+
+        cursor.tag(" from ").unwrap();
+        let error = cursor.integer::<u32>().unwrap_err();
+        assert_eq!((error.line, error.column), (3, 14));
+        assert_eq!(error.found, "x");
+    }
+
+    #[test]
+    fn end_reports_leftovers() {
+        let line = Line::new(1, "ab cd");
+        let mut cursor = line.cursor();
+        cursor.word().unwrap();
+
+        let error = cursor.end().unwrap_err();
+        assert_eq!(error.column, 3);
+        assert_eq!(error.found, " cd");
+
+        let error = Line::new(1, "ab").cursor().until(",").unwrap_err();
+        assert_eq!(error.column, 3);
+        assert_eq!(error.found, "end of line");
+    }
+
+    #[test]
+    fn columns_count_characters() {
+        let line = Line::new(1, "héllo wörld");
+
+        assert_eq!(line.column_of(&line.text()[7..]), 7);
+        // tokens not borrowed from the line are looked up by their text
+        assert_eq!(line.column_of("wörld"), 7);
+        assert_eq!(line.column_of("nope"), 1);
+    }
+
+    #[test]
+    fn lines_drop_carriage_returns() {
+        let input = ["1,2\r", "", "3\r"];
+        let sections = sections(&input).collect_vec();
+
+        assert_eq!(sections.len(), 2);
+        assert_eq!(sections[0][0].parse_list::<u32>(","), Ok(vec![1, 2]));
+        assert_eq!(sections[1][0].number(), 3);
+        assert_eq!(sections[1][0].text(), "3");
+    }
+
+    #[test]
+    fn ascending_range_rejects_descending_ones() {
+        let line = Line::new(2, "x=-3..4,y=5..-1");
+        let mut cursor = line.cursor();
+
+        cursor.tag("x=").unwrap();
+        assert_eq!(cursor.ascending_range::<i32>(), Ok(-3..=4));
+        cursor.tag(",y=").unwrap();
+
+        let error = cursor.ascending_range::<i32>().unwrap_err();
+        assert_eq!((error.line, error.column), (2, 11));
+        assert_eq!(error.expected, "an ascending range");
+        assert_eq!(error.found, "5..-1");
+        assert_eq!(Line::new(1, "7..7").cursor().ascending_range(), Ok(7..=7));
+    }
+}
@@ -0,0 +1,247 @@
+//! Timing baselines used to catch performance regressions.
+
+use std::collections::BTreeMap;
+use std::fmt::{self, Display};
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::str::FromStr;
+use std::time::Duration;
+
+/// Median timings of solution parts, keyed by name (e.g. `d15p2`).
+///
+/// The textual form holds one `<name> <nanoseconds>` pair per line,
+/// which is also the format of saved baseline files.
+#[derive(Clone, Debug, Default)]
+pub struct Timings {
+    entries: BTreeMap<String, Duration>,
+}
+
+/// A part's current timing set against its baseline.
+#[derive(Clone, Debug)]
+pub struct Comparison {
+    pub name: String,
+    pub baseline: Duration,
+    pub current: Duration,
+}
+
+/// Returns the median of a set of samples
+/// (the upper one for an even number of samples).
+///
+/// # Panics
+///
+/// Panics if `samples` is empty.
+pub fn median(samples: &mut [Duration]) -> Duration {
+    assert!(!samples.is_empty(), "Cannot take the median of no samples");
+
+    samples.sort_unstable();
+
+    samples[samples.len() / 2]
+}
+
+impl Timings {
+    /// Creates an empty set of timings.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Reads timings from a baseline file.
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let contents = fs::read_to_string(path)?;
+
+        contents
+            .parse()
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    /// Writes timings to a baseline file, replacing its contents.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        fs::write(path, self.to_string())
+    }
+
+    /// Records the median timing of a part.
+    pub fn insert<S: Into<String>>(&mut self, name: S, median: Duration) {
+        self.entries.insert(name.into(), median);
+    }
+
+    /// Returns the recorded timing of a part.
+    pub fn get(&self, name: &str) -> Option<Duration> {
+        self.entries.get(name).copied()
+    }
+
+    /// Iterates over the recorded timings in name order.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, Duration)> {
+        self.entries.iter().map(|(name, &t)| (name.as_str(), t))
+    }
+
+    /// Compares every timing against the one recorded in `baseline`.
+    ///
+    /// Parts missing from the baseline are skipped, see [`Timings::missing_from`].
+    pub fn compare(&self, baseline: &Self) -> Vec<Comparison> {
+        self.iter()
+            .filter_map(|(name, current)| {
+                baseline.get(name).map(|baseline| Comparison {
+                    name: name.to_string(),
+                    baseline,
+                    current,
+                })
+            })
+            .collect()
+    }
+
+    /// Names of the recorded parts that `baseline` has no timing for.
+    pub fn missing_from(&self, baseline: &Self) -> Vec<&str> {
+        self.iter()
+            .map(|(name, _)| name)
+            .filter(|name| baseline.get(name).is_none())
+            .collect()
+    }
+}
+
+impl Comparison {
+    /// Relative change from the baseline, in percent.
+    /// Positive values mean the part got slower.
+    pub fn change_percent(&self) -> f64 {
+        let baseline = self.baseline.as_secs_f64();
+        let current = self.current.as_secs_f64();
+
+        if baseline == 0.0 {
+            return 0.0;
+        }
+
+        (current - baseline) / baseline * 100.0
+    }
+
+    /// Checks whether the part got slower by more than `threshold` percent.
+    pub fn is_regression(&self, threshold: f64) -> bool {
+        self.change_percent() > threshold
+    }
+}
+
+impl FromStr for Timings {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut result = Self::new();
+
+        for (i, line) in s.lines().enumerate() {
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let parsed = line
+                .split_once(' ')
+                .and_then(|(name, nanos)| Some((name, nanos.trim().parse::<u64>().ok()?)));
+
+            match parsed {
+                Some((name, nanos)) => result.insert(name, Duration::from_nanos(nanos)),
+                None => return Err(format!("Malformed timing on line {}: {:?}", i + 1, line)),
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+impl Display for Timings {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (name, median) in self.iter() {
+            writeln!(f, "{} {}", name, median.as_nanos())?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timings(entries: &[(&str, u64)]) -> Timings {
+        let mut timings = Timings::new();
+
+        for &(name, nanos) in entries {
+            timings.insert(name, Duration::from_nanos(nanos));
+        }
+
+        timings
+    }
+
+    #[test]
+    fn compares_against_the_baseline() {
+        let baseline = timings(&[("d01p1", 1000), ("d01p2", 2000), ("d02p1", 500)]);
+        let current = timings(&[("d01p1", 1100), ("d01p2", 3000), ("d03p1", 70)]);
+
+        let comparisons = current.compare(&baseline);
+        let verdicts = comparisons
+            .iter()
+            .map(|cmp| {
+                (
+                    cmp.name.as_str(),
+                    cmp.change_percent().round(),
+                    cmp.is_regression(20.0),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(verdicts, [("d01p1", 10.0, false), ("d01p2", 50.0, true)]);
+    }
+
+    #[test]
+    fn reports_days_missing_from_the_baseline() {
+        let baseline = timings(&[("d01p1", 1000)]);
+        let current = timings(&[("d01p1", 1000), ("d25p1", 10)]);
+
+        assert_eq!(current.missing_from(&baseline), ["d25p1"]);
+        assert!(baseline.missing_from(&current).is_empty());
+    }
+
+    #[test]
+    fn zero_baselines_never_regress() {
+        let cmp = Comparison {
+            name: "d06p1".to_string(),
+            baseline: Duration::ZERO,
+            current: Duration::from_millis(1),
+        };
+
+        assert!(!cmp.is_regression(0.0));
+    }
+
+    #[test]
+    fn round_trips_baseline_files() {
+        let path = std::env::temp_dir().join(format!("aoc-perf-{}.txt", std::process::id()));
+        let saved = timings(&[("d15p2", 123456789), ("d01p1", 42)]);
+
+        saved.save(&path).unwrap();
+        let loaded = Timings::load(&path);
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(loaded.unwrap().to_string(), "d01p1 42\nd15p2 123456789\n");
+    }
+
+    #[test]
+    fn skips_comments_and_blank_lines() {
+        let parsed: Timings = "# saved by aoc perf\n\nd01p1 42\n".parse().unwrap();
+
+        assert_eq!(parsed.get("d01p1"), Some(Duration::from_nanos(42)));
+    }
+
+    #[test]
+    fn rejects_corrupt_baselines() {
+        for contents in ["d01p1", "d01p1 fast", "d01p1 -5", "d01p1 1 2"] {
+            let error = contents.parse::<Timings>().unwrap_err();
+
+            assert_eq!(error, format!("Malformed timing on line 1: {:?}", contents));
+        }
+
+        let path =
+            std::env::temp_dir().join(format!("aoc-perf-corrupt-{}.txt", std::process::id()));
+        fs::write(&path, "d01p1 42\n\u{0}\u{0}\u{0}\n").unwrap();
+        let loaded = Timings::load(&path);
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(loaded.unwrap_err().kind(), io::ErrorKind::InvalidData);
+    }
+}
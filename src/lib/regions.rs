@@ -0,0 +1,157 @@
+//! Connected regions of grid cells, e.g. basins on a height map.
+
+use crate::grid::{Connectivity, Grid, Pos};
+
+/// A connected region of cells.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Region {
+    /// Number of cells in the region.
+    pub size: usize,
+    /// Top left corner of the bounding box.
+    pub min: Pos,
+    /// Bottom right corner of the bounding box, inclusive.
+    pub max: Pos,
+}
+
+/// The result of labelling a grid's connected regions.
+#[derive(Clone, Debug)]
+pub struct Regions {
+    /// Index into `regions` for every member cell, `None` for the rest.
+    pub labels: Grid<Option<usize>>,
+    /// Regions in the row-major order of their first cell.
+    pub regions: Vec<Region>,
+}
+
+/// Labels the connected regions formed by cells satisfying `member`.
+pub fn label<T, F>(grid: &Grid<T>, connectivity: Connectivity, mut member: F) -> Regions
+where
+    F: FnMut(&T) -> bool,
+{
+    let mut labels = grid.map(|_| None);
+    let mut regions = vec![];
+
+    for pos in grid.positions() {
+        if labels[pos].is_some() || !member(&grid[pos]) {
+            continue;
+        }
+
+        let id = regions.len();
+        let mut region = Region::new(pos);
+        labels[pos] = Some(id);
+
+        labels.flood_fill([pos], connectivity, |p, label| {
+            let enter = label.is_none() && member(&grid[p]);
+
+            if enter {
+                *label = Some(id);
+                region.add(p);
+            }
+
+            enter
+        });
+
+        regions.push(region);
+    }
+
+    Regions { labels, regions }
+}
+
+impl Region {
+    fn new(pos: Pos) -> Self {
+        Self {
+            size: 1,
+            min: pos,
+            max: pos,
+        }
+    }
+
+    fn add(&mut self, (x, y): Pos) {
+        self.size += 1;
+        self.min = (self.min.0.min(x), self.min.1.min(y));
+        self.max = (self.max.0.max(x), self.max.1.max(y));
+    }
+
+    /// Width of the bounding box.
+    pub fn width(&self) -> usize {
+        self.max.0 - self.min.0 + 1
+    }
+
+    /// Height of the bounding box.
+    pub fn height(&self) -> usize {
+        self.max.1 - self.min.1 + 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid;
+
+    const BASINS: [&str; 5] = [
+        "2199943210",
+        "3987894921",
+        "9856789892",
+        "8767896789",
+        "9899965678",
+    ];
+
+    #[test]
+    fn labels_basins() {
+        let heights = grid::parse_digits(&BASINS).unwrap();
+        let basins = label(&heights, Connectivity::Four, |&h| h != 9);
+
+        let sizes = basins.regions.iter().map(|r| r.size).collect::<Vec<_>>();
+        assert_eq!(sizes, [3, 9, 14, 9]);
+
+        assert_eq!(basins.labels[(0, 0)], Some(0));
+        assert_eq!(basins.labels[(9, 0)], Some(1));
+        assert_eq!(basins.labels[(2, 2)], Some(2));
+        assert_eq!(basins.labels[(9, 4)], Some(3));
+        assert_eq!(basins.labels[(2, 0)], None);
+
+        let labelled = basins.labels.iter().filter(|(_, l)| l.is_some()).count();
+        assert_eq!(labelled, sizes.iter().sum::<usize>());
+    }
+
+    #[test]
+    fn bounding_boxes() {
+        let heights = grid::parse_digits(&BASINS).unwrap();
+        let basins = label(&heights, Connectivity::Four, |&h| h != 9);
+
+        let first = basins.regions[0];
+        assert_eq!((first.min, first.max), ((0, 0), (1, 1)));
+        assert_eq!((first.width(), first.height()), (2, 2));
+
+        let middle = basins.regions[2];
+        assert_eq!((middle.min, middle.max), ((0, 1), (5, 4)));
+        assert_eq!((middle.width(), middle.height()), (6, 4));
+    }
+
+    #[test]
+    fn diagonals_join_regions_only_with_eight_neighbours() {
+        let cells = grid::parse_digits(&["100", "010", "001"]).unwrap();
+
+        let four = label(&cells, Connectivity::Four, |&c| c == 1);
+        assert_eq!(four.regions.len(), 3);
+        assert!(four.regions.iter().all(|r| r.size == 1 && r.width() == 1));
+
+        let eight = label(&cells, Connectivity::Eight, |&c| c == 1);
+        assert_eq!(
+            eight.regions,
+            [Region {
+                size: 3,
+                min: (0, 0),
+                max: (2, 2),
+            }]
+        );
+    }
+
+    #[test]
+    fn no_members_no_regions() {
+        let cells = Grid::from_elem(3, 2, 0);
+        let regions = label(&cells, Connectivity::Eight, |&c| c > 0);
+
+        assert!(regions.regions.is_empty());
+        assert!(regions.labels.iter().all(|(_, l)| l.is_none()));
+    }
+}
@@ -0,0 +1,371 @@
+//! Printing answers along with their timings.
+//!
+//! The command line parsed by [`Input`](crate::input::Input) selects the output format
+//! and the parts to run, so solutions only have to hand over the code computing each answer.
+
+use std::collections::HashMap;
+use std::convert::Infallible;
+use std::fmt::{Display, Write};
+use std::str::FromStr;
+use std::sync::OnceLock;
+use std::time::{Duration, Instant};
+
+/// How results are printed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    /// The answer followed by timings, for reading in a terminal.
+    Human,
+    /// A single JSON object per run, for consumption by other tools.
+    Json,
+}
+
+/// The outcome of solving one part, as printed with `--format json`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Record {
+    pub day: u32,
+    pub part: u32,
+    pub answer: String,
+    pub parse_time: Duration,
+    pub solve_time: Duration,
+    pub input_path: String,
+}
+
+/// The run of a solution, as given on its command line.
+#[derive(Clone, Debug)]
+struct Run {
+    day: u32,
+    /// The only part to solve, if one was selected.
+    part: Option<u32>,
+    input_path: String,
+    format: Format,
+    started: Instant,
+}
+
+static RUN: OnceLock<Run> = OnceLock::new();
+/// Time taken to parse the input, i.e. until the first part started.
+static PARSE_TIME: OnceLock<Duration> = OnceLock::new();
+
+impl Format {
+    pub const NAMES: [&'static str; 2] = ["human", "json"];
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "human" => Ok(Self::Human),
+            "json" => Ok(Self::Json),
+            _ => Err(format!("Unknown output format {:?}", s)),
+        }
+    }
+}
+
+/// Registers the run of a solution once its command line is parsed.
+///
+/// Input parsing is timed from this point on until [`measure_and_print`] is first called.
+pub(crate) fn start(day: u32, part: Option<u32>, input_path: String, format: Format) {
+    let run = Run {
+        day,
+        part,
+        input_path,
+        format,
+        started: Instant::now(),
+    };
+
+    // a solution only parses its command line once
+    let _ = RUN.set(run);
+}
+
+/// Computes the answer to `part` with `f` and prints it along with its timings.
+///
+/// The parse time is printed along with the first part solved.
+/// Parts not selected on the command line are skipped.
+pub fn measure_and_print<T: Display, F: FnOnce() -> T>(part: u32, f: F) {
+    let result: Result<(), Infallible> = try_measure_and_print(part, || Ok(f()));
+
+    result.unwrap()
+}
+
+/// Like [`measure_and_print`], for parts that can fail to produce an answer.
+///
+/// Nothing is printed on failure.
+pub fn try_measure_and_print<T, E, F>(part: u32, f: F) -> Result<(), E>
+where
+    T: Display,
+    F: FnOnce() -> Result<T, E>,
+{
+    let run = RUN.get();
+
+    if run.and_then(|run| run.part).is_some_and(|p| p != part) {
+        return Ok(());
+    }
+
+    let mut first = false;
+    let parse_time = run.map(|run| {
+        *PARSE_TIME.get_or_init(|| {
+            first = true;
+            run.started.elapsed()
+        })
+    });
+
+    let start = Instant::now();
+    let answer = f()?;
+    let solve_time = start.elapsed();
+
+    match run {
+        Some(run) if run.format == Format::Json => {
+            let record = Record {
+                day: run.day,
+                part,
+                answer: answer.to_string(),
+                parse_time: parse_time.unwrap_or_default(),
+                solve_time,
+                input_path: run.input_path.clone(),
+            };
+
+            println!("{}", record.to_json());
+        }
+        _ => {
+            let answer = answer.to_string();
+
+            // multi-line answers like ASCII art start on a line of their own
+            match answer.contains('\n') {
+                true => println!("Part {}:\n{}", part, answer),
+                false => println!("Part {}: {}", part, answer),
+            }
+
+            match parse_time {
+                Some(parse_time) if first => {
+                    println!("Elapsed: {:?} (parsing: {:?})", solve_time, parse_time)
+                }
+                _ => println!("Elapsed: {:?}", solve_time),
+            }
+        }
+    }
+
+    Ok(())
+}
+
+impl Record {
+    /// Formats the record as a single line JSON object.
+    pub fn to_json(&self) -> String {
+        // integers written the canonical way stay numbers, anything else
+        // (e.g. ASCII art, or `+5` and `007` which aren't valid JSON numbers) becomes a string
+        let answer = match self.answer.parse::<i128>() {
+            Ok(n) if n.to_string() == self.answer => self.answer.clone(),
+            _ => json_string(&self.answer),
+        };
+        format!(
+            r#"{{"day":{},"part":{},"answer":{},"parse_ns":{},"solve_ns":{},"input_path":{}}}"#,
+            self.day,
+            self.part,
+            answer,
+            self.parse_time.as_nanos(),
+            self.solve_time.as_nanos(),
+            json_string(&self.input_path)
+        )
+    }
+}
+
+impl FromStr for Record {
+    type Err = String;
+
+    /// Reads back a record printed by [`Record::to_json`].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut rest = s
+            .trim()
+            .strip_prefix('{')
+            .and_then(|s| s.strip_suffix('}'))
+            .ok_or_else(|| format!("Expected a JSON object, found {:?}", s))?;
+        let mut fields = HashMap::new();
+
+        while !rest.is_empty() {
+            let (key, tail) = take_json_string(rest)?;
+            let tail = tail
+                .strip_prefix(':')
+                .ok_or_else(|| format!("Expected `:` after {:?}", key))?;
+
+            let (value, tail) = if tail.starts_with('"') {
+                take_json_string(tail)?
+            } else {
+                let end = tail.find(',').unwrap_or(tail.len());
+
+                (tail[..end].to_string(), &tail[end..])
+            };
+
+            fields.insert(key, value);
+            rest = tail.strip_prefix(',').unwrap_or(tail);
+        }
+
+        let mut field = |key: &str| {
+            fields
+                .remove(key)
+                .ok_or_else(|| format!("Missing field {:?}", key))
+        };
+        fn number<T: FromStr>(key: &str, value: String) -> Result<T, String> {
+            value
+                .parse()
+                .map_err(|_| format!("Expected a number for {:?}, found {:?}", key, value))
+        }
+
+        let day = number("day", field("day")?)?;
+        let part = number("part", field("part")?)?;
+        let answer = field("answer")?;
+        let parse_time = Duration::from_nanos(number("parse_ns", field("parse_ns")?)?);
+        let solve_time = Duration::from_nanos(number("solve_ns", field("solve_ns")?)?);
+        let input_path = field("input_path")?;
+
+        Ok(Self {
+            day,
+            part,
+            answer,
+            parse_time,
+            solve_time,
+            input_path,
+        })
+    }
+}
+
+fn json_string(s: &str) -> String {
+    let mut result = String::with_capacity(s.len() + 2);
+    result.push('"');
+
+    for c in s.chars() {
+        match c {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            '\t' => result.push_str("\\t"),
+            c if c.is_control() => write!(result, "\\u{:04x}", c as u32).unwrap(),
+            c => result.push(c),
+        }
+    }
+
+    result.push('"');
+    result
+}
+
+/// Splits a leading JSON string literal off `s`, returning it unescaped.
+fn take_json_string(s: &str) -> Result<(String, &str), String> {
+    let body = s
+        .strip_prefix('"')
+        .ok_or_else(|| format!("Expected a string, found {:?}", s))?;
+    let mut result = String::new();
+    let mut chars = body.char_indices();
+
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '"' => return Ok((result, &body[i + 1..])),
+            '\\' => {
+                let escaped = match chars.next().map(|(_, c)| c) {
+                    Some('n') => '\n',
+                    Some('r') => '\r',
+                    Some('t') => '\t',
+                    Some('b') => '\u{8}',
+                    Some('f') => '\u{c}',
+                    Some('u') => {
+                        let hex: String = chars.by_ref().take(4).map(|(_, c)| c).collect();
+
+                        u32::from_str_radix(&hex, 16)
+                            .ok()
+                            .and_then(char::from_u32)
+                            .ok_or_else(|| format!("Invalid escape \\u{}", hex))?
+                    }
+                    Some(c @ ('"' | '\\' | '/')) => c,
+                    Some(c) => return Err(format!("Invalid escape \\{}", c)),
+                    None => break,
+                };
+
+                result.push(escaped);
+            }
+            c => result.push(c),
+        }
+    }
+
+    Err(format!("Unterminated string {:?}", s))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(answer: &str, input_path: &str) -> Record {
+        Record {
+            day: 13,
+            part: 2,
+            answer: answer.to_string(),
+            parse_time: Duration::from_nanos(1500),
+            solve_time: Duration::from_nanos(27),
+            input_path: input_path.to_string(),
+        }
+    }
+
+    #[test]
+    fn integer_answers_stay_numbers() {
+        let json = record("-42", "input/day13.txt").to_json();
+
+        assert_eq!(
+            json,
+            r#"{"day":13,"part":2,"answer":-42,"parse_ns":1500,"solve_ns":27,"input_path":"input/day13.txt"}"#
+        );
+        assert_eq!(json.parse(), Ok(record("-42", "input/day13.txt")));
+
+        for answer in ["+5", "007", "1e3", ""] {
+            assert!(record(answer, "")
+                .to_json()
+                .contains(&format!(r#""answer":"{}""#, answer)));
+        }
+    }
+
+    #[test]
+    fn round_trips_escaped_strings() {
+        let art = "#..#\n\"quoted\" \\ back\tslash\r\u{1}";
+        let original = record(art, "C:\\inputs\\day 13, \"odd\".txt");
+        let json = original.to_json();
+
+        assert!(!json.contains('\n'));
+        assert!(json.contains(r"\u0001"));
+        assert_eq!(json.parse(), Ok(original));
+    }
+
+    #[test]
+    fn reads_standard_escapes() {
+        let json = r#"{"day":1,"part":1,"answer":"a\/b\bc\fd\u00e9","parse_ns":0,"solve_ns":0,"input_path":"-"}"#;
+        let parsed: Record = json.parse().unwrap();
+
+        assert_eq!(parsed.answer, "a/b\u{8}c\u{c}d\u{e9}");
+    }
+
+    #[test]
+    fn reports_missing_fields() {
+        let json = r#"{"day":1,"part":1,"parse_ns":0,"solve_ns":0,"input_path":"-"}"#;
+
+        assert_eq!(
+            json.parse::<Record>(),
+            Err(r#"Missing field "answer""#.to_string())
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_records() {
+        let valid = record("1", "-").to_json();
+        let malformed = [
+            "",
+            "[]",
+            &valid[..valid.len() - 1],
+            &valid.replace(r#""day":13"#, r#""day":"x""#),
+            &valid.replace(r#""day":13"#, r#""day":4294967309"#),
+            &valid.replace(r#""solve_ns":27"#, r#""solve_ns":-27"#),
+            &valid.replace(r#""day":"#, r#""day""#),
+            &valid.replace(r#""-""#, r#""-"#),
+            &valid.replace(r#""-""#, r#""\q""#),
+            &valid.replace(r#""-""#, r#""\u12""#),
+        ];
+
+        for json in malformed {
+            assert!(json.parse::<Record>().is_err(), "accepted {:?}", json);
+        }
+    }
+}
@@ -0,0 +1,328 @@
+//! Shortest path searches over implicit graphs.
+//!
+//! Graphs are described by a successor function returning the neighbours of
+//! a node along with the cost of moving to each of them, so the searches work
+//! equally well for grids, caves and puzzle states.
+//!
+//! Reached nodes are kept in a hash map by default. Searches over a grid can
+//! keep them in a [`GridCosts`] instead, which is much cheaper on large maps.
+
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::hash::Hash;
+use std::ops::Add;
+
+use rustc_hash::FxHashMap;
+
+use crate::grid::Pos;
+
+/// The cheapest path found by a search, from one of the sources to a target.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Path<N, C> {
+    pub cost: C,
+    /// Visited nodes, starting with the source and ending with the target.
+    pub nodes: Vec<N>,
+}
+
+/// Storage for the best known cost of every node reached by a search,
+/// along with the node it was reached from.
+pub trait Costs<N, C> {
+    /// The best known cost of `node`, or `None` if it has not been reached yet.
+    fn cost(&self, node: &N) -> Option<C>;
+
+    /// The node `node` was reached from, or `None` for sources and unreached nodes.
+    fn predecessor(&self, node: &N) -> Option<N>;
+
+    fn insert(&mut self, node: N, cost: C, predecessor: Option<N>);
+}
+
+impl<N, C> Costs<N, C> for FxHashMap<N, (C, Option<N>)>
+where
+    N: Clone + Eq + Hash,
+    C: Copy,
+{
+    fn cost(&self, node: &N) -> Option<C> {
+        self.get(node).map(|&(cost, _)| cost)
+    }
+
+    fn predecessor(&self, node: &N) -> Option<N> {
+        self.get(node).and_then(|(_, prev)| prev.clone())
+    }
+
+    fn insert(&mut self, node: N, cost: C, predecessor: Option<N>) {
+        FxHashMap::insert(self, node, (cost, predecessor));
+    }
+}
+
+/// Costs of grid positions, stored densely in row-major order.
+#[derive(Clone, Debug)]
+pub struct GridCosts<C> {
+    width: usize,
+    costs: Vec<Option<C>>,
+    /// Index of the predecessor of every cell, or `usize::MAX` for none.
+    predecessors: Vec<usize>,
+}
+
+impl<C: Copy> GridCosts<C> {
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            costs: vec![None; width * height],
+            predecessors: vec![usize::MAX; width * height],
+        }
+    }
+
+    fn index(&self, (x, y): Pos) -> usize {
+        y * self.width + x
+    }
+}
+
+impl<C: Copy> Costs<Pos, C> for GridCosts<C> {
+    fn cost(&self, &pos: &Pos) -> Option<C> {
+        self.costs[self.index(pos)]
+    }
+
+    fn predecessor(&self, &pos: &Pos) -> Option<Pos> {
+        let prev = self.predecessors[self.index(pos)];
+
+        (prev != usize::MAX).then(|| (prev % self.width, prev / self.width))
+    }
+
+    fn insert(&mut self, pos: Pos, cost: C, predecessor: Option<Pos>) {
+        let i = self.index(pos);
+
+        self.costs[i] = Some(cost);
+        self.predecessors[i] = predecessor.map_or(usize::MAX, |prev| self.index(prev));
+    }
+}
+
+/// A node waiting in the open set, ordered so that `BinaryHeap` pops
+/// the lowest estimated total cost first.
+struct Candidate<N, C> {
+    estimate: C,
+    cost: C,
+    node: N,
+}
+
+/// Finds the cheapest path from any of `sources` to a node satisfying `is_target`.
+///
+/// Step costs must not be negative. `C::default()` is used as zero cost.
+/// Returns `None` if no target is reachable.
+pub fn dijkstra<N, C, I, FN, FT>(
+    sources: impl IntoIterator<Item = N>,
+    successors: FN,
+    is_target: FT,
+) -> Option<Path<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (N, C)>,
+    FN: FnMut(&N) -> I,
+    FT: FnMut(&N) -> bool,
+{
+    astar(sources, successors, |_| C::default(), is_target)
+}
+
+/// Same as [`dijkstra`], keeping the reached nodes in `costs`.
+pub fn dijkstra_in<N, C, S, I, FN, FT>(
+    costs: S,
+    sources: impl IntoIterator<Item = N>,
+    successors: FN,
+    is_target: FT,
+) -> Option<Path<N, C>>
+where
+    N: Clone + Eq,
+    C: Copy + Ord + Add<Output = C> + Default,
+    S: Costs<N, C>,
+    I: IntoIterator<Item = (N, C)>,
+    FN: FnMut(&N) -> I,
+    FT: FnMut(&N) -> bool,
+{
+    astar_in(costs, sources, successors, |_| C::default(), is_target)
+}
+
+/// Finds the cheapest path from any of `sources` to a node satisfying `is_target`,
+/// guided by `heuristic`.
+///
+/// The heuristic must never overestimate the remaining cost to the nearest target,
+/// otherwise the returned path may not be the cheapest one.
+/// Returns `None` if no target is reachable.
+pub fn astar<N, C, I, FN, FH, FT>(
+    sources: impl IntoIterator<Item = N>,
+    successors: FN,
+    heuristic: FH,
+    is_target: FT,
+) -> Option<Path<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (N, C)>,
+    FN: FnMut(&N) -> I,
+    FH: FnMut(&N) -> C,
+    FT: FnMut(&N) -> bool,
+{
+    let costs: FxHashMap<N, (C, Option<N>)> = FxHashMap::default();
+
+    astar_in(costs, sources, successors, heuristic, is_target)
+}
+
+/// Same as [`astar`], keeping the reached nodes in `costs`.
+pub fn astar_in<N, C, S, I, FN, FH, FT>(
+    mut costs: S,
+    sources: impl IntoIterator<Item = N>,
+    mut successors: FN,
+    mut heuristic: FH,
+    mut is_target: FT,
+) -> Option<Path<N, C>>
+where
+    N: Clone + Eq,
+    C: Copy + Ord + Add<Output = C> + Default,
+    S: Costs<N, C>,
+    I: IntoIterator<Item = (N, C)>,
+    FN: FnMut(&N) -> I,
+    FH: FnMut(&N) -> C,
+    FT: FnMut(&N) -> bool,
+{
+    let zero = C::default();
+    let mut open = BinaryHeap::new();
+
+    for source in sources {
+        if costs.cost(&source).is_none() {
+            costs.insert(source.clone(), zero, None);
+            open.push(Candidate {
+                estimate: heuristic(&source),
+                cost: zero,
+                node: source,
+            });
+        }
+    }
+
+    while let Some(Candidate { cost, node, .. }) = open.pop() {
+        if costs.cost(&node).is_some_and(|best| cost > best) {
+            continue;
+        }
+
+        if is_target(&node) {
+            let nodes = reconstruct(&costs, node);
+
+            return Some(Path { cost, nodes });
+        }
+
+        for (next, step) in successors(&node) {
+            let next_cost = cost + step;
+
+            if costs.cost(&next).is_none_or(|best| next_cost < best) {
+                costs.insert(next.clone(), next_cost, Some(node.clone()));
+                open.push(Candidate {
+                    estimate: next_cost + heuristic(&next),
+                    cost: next_cost,
+                    node: next,
+                });
+            }
+        }
+    }
+
+    None
+}
+
+fn reconstruct<N, C, S: Costs<N, C>>(costs: &S, target: N) -> Vec<N> {
+    let mut nodes = vec![target];
+
+    while let Some(prev) = costs.predecessor(nodes.last().unwrap()) {
+        nodes.push(prev);
+    }
+
+    nodes.reverse();
+    nodes
+}
+
+impl<N, C: Ord> Ord for Candidate<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // reversed to turn the max-heap into a min-heap,
+        // preferring the deeper node on equal estimates
+        other
+            .estimate
+            .cmp(&self.estimate)
+            .then_with(|| self.cost.cmp(&other.cost))
+    }
+}
+
+impl<N, C: Ord> PartialOrd for Candidate<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N, C: Ord> PartialEq for Candidate<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<N, C: Ord> Eq for Candidate<N, C> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::{self, Grid};
+
+    const RISKS: [&str; 10] = [
+        "1163751742",
+        "1381373672",
+        "2136511328",
+        "3694931569",
+        "7463417111",
+        "1319128137",
+        "1359912421",
+        "3125421639",
+        "1293138521",
+        "2311944581",
+    ];
+
+    fn successors(risks: &Grid<u32>) -> impl FnMut(&Pos) -> Vec<(Pos, u32)> + '_ {
+        |&pos| {
+            risks
+                .neighbors4(pos)
+                .map(|next| (next, risks[next]))
+                .collect()
+        }
+    }
+
+    #[test]
+    fn dijkstra_finds_lowest_risk() {
+        let risks = grid::parse_digits(&RISKS).unwrap();
+        let end = (risks.width() - 1, risks.height() - 1);
+
+        let path = dijkstra([(0, 0)], successors(&risks), |&pos| pos == end).unwrap();
+
+        assert_eq!(path.cost, 40);
+        assert_eq!(path.nodes.first(), Some(&(0, 0)));
+        assert_eq!(path.nodes.last(), Some(&end));
+        assert_eq!(path.nodes[1..].iter().map(|&p| risks[p]).sum::<u32>(), 40);
+    }
+
+    #[test]
+    fn astar_and_grid_costs_agree() {
+        let risks = grid::parse_digits(&RISKS).unwrap();
+        let end = (risks.width() - 1, risks.height() - 1);
+        let heuristic = |&(x, y): &Pos| (end.0 - x + end.1 - y) as u32;
+
+        let path = astar([(0, 0)], successors(&risks), heuristic, |&pos| pos == end);
+        assert_eq!(path.map(|p| p.cost), Some(40));
+
+        let costs = GridCosts::new(risks.width(), risks.height());
+        let path = dijkstra_in(costs, [(0, 0)], successors(&risks), |&pos| pos == end);
+        assert_eq!(path.map(|p| p.cost), Some(40));
+    }
+
+    #[test]
+    fn unreachable_target() {
+        let path = dijkstra(
+            [0u32],
+            |&n| [(n + 1, 1u32)].into_iter().filter(|&(n, _)| n < 5),
+            |&n| n == 9,
+        );
+
+        assert_eq!(path, None);
+    }
+}
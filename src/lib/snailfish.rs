@@ -0,0 +1,274 @@
+//! Snailfish number arithmetic.
+//!
+//! A snailfish number is a pair whose elements are regular numbers or other pairs.
+//! It is stored flat as its regular numbers from left to right, each with the
+//! number of pairs enclosing it, which turns exploding into a look at the neighbours.
+
+use std::fmt::{self, Display};
+use std::ops::Add;
+use std::str::FromStr;
+
+use crate::parse::{self, ParseError};
+
+/// Pairs nested inside this many pairs explode when reducing.
+const MAX_DEPTH: u8 = 4;
+/// Regular numbers at least this large split when reducing.
+const SPLIT_THRESHOLD: u32 = 10;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Cell {
+    value: u32,
+    depth: u8,
+}
+
+/// A snailfish number, kept reduced by addition.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Number {
+    cells: Vec<Cell>,
+}
+
+impl Number {
+    /// Parses a number written in the nested pair syntax, like `[[1,2],3]`.
+    pub fn parse(line: parse::Line) -> Result<Self, ParseError> {
+        let mut cursor = line.cursor();
+        let mut cells = vec![];
+
+        parse_pair(&mut cursor, 0, &mut cells)?;
+        cursor.end()?;
+
+        Ok(Self { cells })
+    }
+
+    /// The magnitude of a pair is 3 times its left element plus 2 times its right one.
+    pub fn magnitude(&self) -> u64 {
+        let (magnitude, _) = self.fold(0, 0, &mut |cell| u64::from(cell.value), &mut |l, r| {
+            3 * l + 2 * r
+        });
+
+        magnitude
+    }
+
+    /// Applies explodes and splits until neither is possible.
+    pub fn reduce(&mut self) {
+        while self.explode() || self.split() {}
+    }
+
+    /// Explodes the leftmost pair nested too deep, returning whether there was one.
+    ///
+    /// The leftmost pair of two regular numbers found below [`MAX_DEPTH`] is the one
+    /// to explode, since any pair to its left would contain such a pair itself.
+    fn explode(&mut self) -> bool {
+        let i = match self
+            .cells
+            .windows(2)
+            .position(|w| w[0].depth > MAX_DEPTH && w[0].depth == w[1].depth)
+        {
+            Some(i) => i,
+            None => return false,
+        };
+
+        let (left, right) = (self.cells[i], self.cells[i + 1]);
+
+        if let Some(prev) = i.checked_sub(1) {
+            self.cells[prev].value += left.value;
+        }
+        if let Some(next) = self.cells.get_mut(i + 2) {
+            next.value += right.value;
+        }
+
+        self.cells.splice(
+            i..i + 2,
+            [Cell {
+                value: 0,
+                depth: left.depth - 1,
+            }],
+        );
+
+        true
+    }
+
+    /// Splits the leftmost regular number that is too large, returning whether there was one.
+    fn split(&mut self) -> bool {
+        let i = match self
+            .cells
+            .iter()
+            .position(|cell| cell.value >= SPLIT_THRESHOLD)
+        {
+            Some(i) => i,
+            None => return false,
+        };
+
+        let Cell { value, depth } = self.cells[i];
+        let halves = [value / 2, value.div_ceil(2)].map(|value| Cell {
+            value,
+            depth: depth + 1,
+        });
+
+        self.cells.splice(i..=i, halves);
+
+        true
+    }
+
+    /// Folds the element starting at cell `i` and `depth` pairs deep back into a tree,
+    /// returning the result and the index of the first cell after the element.
+    fn fold<T, L, P>(&self, i: usize, depth: u8, leaf: &mut L, pair: &mut P) -> (T, usize)
+    where
+        L: FnMut(Cell) -> T,
+        P: FnMut(T, T) -> T,
+    {
+        let cell = self.cells[i];
+
+        if cell.depth == depth {
+            return (leaf(cell), i + 1);
+        }
+
+        let (left, i) = self.fold(i, depth + 1, leaf, pair);
+        let (right, i) = self.fold(i, depth + 1, leaf, pair);
+
+        (pair(left, right), i)
+    }
+}
+
+/// Parses a pair enclosed in `depth` other pairs.
+///
+/// Nesting is limited so that adding the number, which nests it in one more pair, keeps
+/// its depths within a `u8`.
+fn parse_pair(
+    cursor: &mut parse::Cursor,
+    depth: u8,
+    cells: &mut Vec<Cell>,
+) -> Result<(), ParseError> {
+    let depth = depth
+        .checked_add(1)
+        .filter(|&depth| depth < u8::MAX)
+        .ok_or_else(|| cursor.line().error(cursor.rest(), "a shallower pair"))?;
+
+    cursor.tag("[")?;
+    parse_element(cursor, depth, cells)?;
+    cursor.tag(",")?;
+    parse_element(cursor, depth, cells)?;
+    cursor.tag("]")
+}
+
+fn parse_element(
+    cursor: &mut parse::Cursor,
+    depth: u8,
+    cells: &mut Vec<Cell>,
+) -> Result<(), ParseError> {
+    if cursor.rest().starts_with('[') {
+        parse_pair(cursor, depth, cells)?;
+    } else {
+        let value = cursor.integer()?;
+
+        cells.push(Cell { value, depth });
+    }
+
+    Ok(())
+}
+
+impl FromStr for Number {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(parse::Line::new(1, s))
+    }
+}
+
+impl Add for Number {
+    type Output = Number;
+
+    /// Pairs up both numbers and reduces the result.
+    fn add(mut self, rhs: Self) -> Self::Output {
+        self.cells.extend(rhs.cells);
+
+        for cell in &mut self.cells {
+            cell.depth += 1;
+        }
+
+        self.reduce();
+
+        self
+    }
+}
+
+impl Add for &Number {
+    type Output = Number;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.clone() + rhs.clone()
+    }
+}
+
+impl Display for Number {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (text, _) = self.fold(0, 0, &mut |cell| cell.value.to_string(), &mut |l, r| {
+            format!("[{},{}]", l, r)
+        });
+
+        f.write_str(&text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn number(s: &str) -> Number {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn explode() {
+        for (before, after) in [
+            ("[[[[[9,8],1],2],3],4]", "[[[[0,9],2],3],4]"),
+            ("[7,[6,[5,[4,[3,2]]]]]", "[7,[6,[5,[7,0]]]]"),
+            ("[[6,[5,[4,[3,2]]]],1]", "[[6,[5,[7,0]]],3]"),
+            (
+                "[[3,[2,[1,[7,3]]]],[6,[5,[4,[3,2]]]]]",
+                "[[3,[2,[8,0]]],[9,[5,[4,[3,2]]]]]",
+            ),
+        ] {
+            let mut n = number(before);
+
+            assert!(n.explode());
+            assert_eq!(n.to_string(), after);
+        }
+
+        assert!(!number("[[[[0,9],2],3],4]").explode());
+    }
+
+    #[test]
+    fn split() {
+        let mut n = number("[[[[0,7],4],[15,[0,13]]],[1,1]]");
+
+        assert!(!n.explode());
+        assert!(n.split());
+        assert_eq!(n.to_string(), "[[[[0,7],4],[[7,8],[0,13]]],[1,1]]");
+        assert!(n.split());
+        assert_eq!(n.to_string(), "[[[[0,7],4],[[7,8],[0,[6,7]]]],[1,1]]");
+    }
+
+    #[test]
+    fn add_reduces() {
+        let sum = number("[[[[4,3],4],4],[7,[[8,4],9]]]") + number("[1,1]");
+
+        assert_eq!(sum.to_string(), "[[[[0,7],4],[[7,8],[6,0]]],[8,1]]");
+    }
+
+    #[test]
+    fn magnitude() {
+        assert_eq!(number("[[1,2],[[3,4],5]]").magnitude(), 143);
+        assert_eq!(
+            number("[[[[8,7],[7,7]],[[8,6],[7,7]]],[[[0,7],[6,6]],[8,7]]]").magnitude(),
+            3488
+        );
+    }
+
+    #[test]
+    fn rejects_nesting_too_deep_to_add() {
+        let nested = |depth| (1..depth).fold("[1,2]".to_string(), |n, _| format!("[{},0]", n));
+
+        assert!(nested(254).parse::<Number>().is_ok());
+        assert_eq!(nested(255).parse::<Number>().unwrap_err().column, 255);
+    }
+}
@@ -0,0 +1,410 @@
+//! The submarine's steering commands.
+//!
+//! Programs are lists of commands, with `back`, `reset-aim` and `repeat` blocks
+//! only accepted by the extended syntax:
+//!
+//! ```text
+//! forward 5
+//! repeat 3
+//!   down 2
+//!   forward 1
+//! end
+//! back 2
+//! reset-aim
+//! ```
+//!
+//! The same program can be run in either [`Mode`], and [`trace`] visits every
+//! position on the way, which [`render_profile`] draws without keeping them around.
+
+use std::error::Error;
+use std::fmt::{self, Display};
+
+use crate::grid::Grid;
+use crate::parse::{self, ParseError};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Command {
+    Forward(i64),
+    Down(i64),
+    Up(i64),
+    /// Undoes moving forward by the same amount.
+    Back(i64),
+    ResetAim,
+    Repeat(usize, Vec<Command>),
+}
+
+/// How the submarine interprets `down` and `up`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Mode {
+    /// `down` and `up` change the depth right away, as first assumed on day 2.
+    Direct,
+    /// `down` and `up` change the aim, and moving forward dives along it.
+    Aimed,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct State {
+    pub horizontal: i64,
+    pub depth: i64,
+    pub aim: i64,
+}
+
+/// The position or aim of the submarine no longer fits an `i64`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OutOfRange;
+
+impl Display for OutOfRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "The submarine's position overflows a 64-bit integer")
+    }
+}
+
+impl Error for OutOfRange {}
+
+/// Parses one command per line, only accepting the extra commands when `extended`.
+pub fn parse_program<S: AsRef<str>>(
+    lines: &[S],
+    extended: bool,
+) -> Result<Vec<Command>, ParseError> {
+    parse_block(&mut parse::lines(lines), extended, None)
+}
+
+/// Parses commands up to the `end` of the `repeat` started on line `opened`,
+/// or up to the end of input at the top level.
+fn parse_block<'a, I>(
+    lines: &mut I,
+    extended: bool,
+    opened: Option<usize>,
+) -> Result<Vec<Command>, ParseError>
+where
+    I: Iterator<Item = parse::Line<'a>>,
+{
+    let mut block = vec![];
+    let mut last_line = 0;
+
+    while let Some(line) = lines.next() {
+        last_line = line.number();
+
+        let mut cursor = line.cursor();
+        // repeat bodies may be indented
+        cursor.take_while("indentation", char::is_whitespace).ok();
+        let name = cursor.take_while("a command", |c| c.is_alphanumeric() || c == '-')?;
+
+        let command = match name {
+            "forward" | "down" | "up" => {
+                cursor.tag(" ")?;
+                let n = distance(&mut cursor)?;
+
+                match name {
+                    "forward" => Command::Forward(n),
+                    "down" => Command::Down(n),
+                    _ => Command::Up(n),
+                }
+            }
+            "back" if extended => {
+                cursor.tag(" ")?;
+                Command::Back(distance(&mut cursor)?)
+            }
+            "reset-aim" if extended => Command::ResetAim,
+            "repeat" if extended => {
+                cursor.tag(" ")?;
+                let times = cursor.integer()?;
+                cursor.end()?;
+
+                let body = parse_block(lines, extended, Some(line.number()))?;
+                block.push(Command::Repeat(times, body));
+                continue;
+            }
+            "end" if extended && opened.is_some() => {
+                cursor.end()?;
+                return Ok(block);
+            }
+            _ if extended => {
+                return Err(line.error(name, "forward, down, up, back, reset-aim or repeat"))
+            }
+            _ => return Err(line.error(name, "forward, down or up")),
+        };
+
+        cursor.end()?;
+        block.push(command);
+    }
+
+    match opened {
+        Some(start) => Err(ParseError::end_of_input(
+            last_line + 1,
+            format!("`end` of the repeat on line {}", start),
+        )),
+        None => Ok(block),
+    }
+}
+
+/// Consumes the distance of a move, which can't be negative.
+fn distance(cursor: &mut parse::Cursor) -> Result<i64, ParseError> {
+    let (n, token) = cursor.consumed(|c| c.integer::<i64>())?;
+
+    if n < 0 {
+        return Err(cursor.line().error(token, "a non-negative distance"));
+    }
+
+    Ok(n)
+}
+
+fn execute<F: FnMut(State)>(
+    program: &[Command],
+    mode: Mode,
+    state: &mut State,
+    visit: &mut F,
+) -> Result<(), OutOfRange> {
+    let add = |a: i64, b: i64| a.checked_add(b).ok_or(OutOfRange);
+    let sub = |a: i64, b: i64| a.checked_sub(b).ok_or(OutOfRange);
+    let mul = |a: i64, b: i64| a.checked_mul(b).ok_or(OutOfRange);
+
+    for command in program {
+        match (command, mode) {
+            (Command::Repeat(times, body), _) => {
+                for _ in 0..*times {
+                    execute(body, mode, state, visit)?;
+                }
+
+                continue;
+            }
+            (Command::Forward(n), Mode::Direct) => state.horizontal = add(state.horizontal, *n)?,
+            (Command::Forward(n), Mode::Aimed) => {
+                state.horizontal = add(state.horizontal, *n)?;
+                state.depth = add(state.depth, mul(state.aim, *n)?)?;
+            }
+            (Command::Back(n), Mode::Direct) => state.horizontal = sub(state.horizontal, *n)?,
+            (Command::Back(n), Mode::Aimed) => {
+                state.horizontal = sub(state.horizontal, *n)?;
+                state.depth = sub(state.depth, mul(state.aim, *n)?)?;
+            }
+            (Command::Down(n), Mode::Direct) => state.depth = add(state.depth, *n)?,
+            (Command::Up(n), Mode::Direct) => state.depth = sub(state.depth, *n)?,
+            (Command::Down(n), Mode::Aimed) => state.aim = add(state.aim, *n)?,
+            (Command::Up(n), Mode::Aimed) => state.aim = sub(state.aim, *n)?,
+            (Command::ResetAim, _) => state.aim = 0,
+        }
+
+        visit(*state);
+    }
+
+    Ok(())
+}
+
+/// Runs the program from the surface, returning where the submarine ends up.
+pub fn run(program: &[Command], mode: Mode) -> Result<State, OutOfRange> {
+    trace(program, mode, |_| {})
+}
+
+/// Runs the program from the surface, handing the starting position followed by
+/// the position after every command to `visit`, and returning where it ends up.
+///
+/// Positions are visited as they are reached, so repeated blocks don't pile up in memory.
+pub fn trace<F: FnMut(State)>(
+    program: &[Command],
+    mode: Mode,
+    mut visit: F,
+) -> Result<State, OutOfRange> {
+    let mut state = State::default();
+    visit(state);
+    execute(program, mode, &mut state, &mut visit)?;
+
+    Ok(state)
+}
+
+/// Draws the depth of the submarine along its horizontal position while running
+/// the program, shrunk to fit `width` by `height` characters, with the surface drawn as `~`.
+///
+/// The program is traced twice, once to find the bounds of the drawing and once to draw it.
+pub fn render_profile(
+    program: &[Command],
+    mode: Mode,
+    width: usize,
+    height: usize,
+) -> Result<String, OutOfRange> {
+    let (width, height) = (width.max(2), height.max(2));
+
+    // the bounds include the surface at 0
+    let (mut min_x, mut max_x, mut min_depth, mut max_depth) = (0, 0, 0, 0);
+    trace(program, mode, |state| {
+        (min_x, max_x) = (min_x.min(state.horizontal), max_x.max(state.horizontal));
+        (min_depth, max_depth) = (min_depth.min(state.depth), max_depth.max(state.depth));
+    })?;
+
+    let scale = |value: i64, min: i64, max: i64, cells: usize| -> usize {
+        let span = (max as i128 - min as i128).max(1);
+
+        ((value as i128 - min as i128) * (cells as i128 - 1) / span) as usize
+    };
+    let to_cell = |state: &State| {
+        (
+            scale(state.horizontal, min_x, max_x, width),
+            scale(state.depth, min_depth, max_depth, height),
+        )
+    };
+
+    let mut canvas = Grid::from_elem(width, height, ' ');
+    let surface = scale(0, min_depth, max_depth, height);
+
+    for x in 0..width {
+        canvas[(x, surface)] = '~';
+    }
+
+    // connect consecutive positions so that large moves still show up as lines
+    let mut previous = None;
+    let end = trace(program, mode, |state| {
+        let (x2, y2) = to_cell(&state);
+        let (x1, y1) = previous.replace((x2, y2)).unwrap_or((x2, y2));
+        let steps = x1.abs_diff(x2).max(y1.abs_diff(y2)).max(1);
+
+        for i in 0..=steps {
+            let along = |from: usize, to: usize| {
+                (from as isize + (to as isize - from as isize) * i as isize / steps as isize)
+                    as usize
+            };
+
+            canvas[(along(x1, x2), along(y1, y2))] = '#';
+        }
+    })?;
+
+    canvas[to_cell(&State::default())] = 'S';
+    canvas[to_cell(&end)] = 'E';
+
+    Ok(format!(
+        "depth {}..{}, horizontal position {}..{}\n{}",
+        min_depth, max_depth, min_x, max_x, canvas
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: [&str; 6] = [
+        "forward 5",
+        "down 5",
+        "forward 8",
+        "up 3",
+        "down 8",
+        "forward 2",
+    ];
+
+    fn product(state: State) -> i64 {
+        state.horizontal * state.depth
+    }
+
+    #[test]
+    fn example() {
+        let program = parse_program(&EXAMPLE, false).unwrap();
+
+        assert_eq!(run(&program, Mode::Direct).map(product), Ok(150));
+        assert_eq!(run(&program, Mode::Aimed).map(product), Ok(900));
+    }
+
+    #[test]
+    fn extended_commands() {
+        let lines = [
+            "forward 5",
+            "repeat 3",
+            "  down 2",
+            "  forward 1",
+            "end",
+            "back 2",
+            "reset-aim",
+            "forward 4",
+        ];
+        let program = parse_program(&lines, true).unwrap();
+
+        assert_eq!(
+            run(&program, Mode::Direct),
+            Ok(State {
+                horizontal: 10,
+                depth: 6,
+                aim: 0
+            })
+        );
+        // aims 2, 4 and 6 while moving forward, then backs up along an aim of 6
+        assert_eq!(
+            run(&program, Mode::Aimed),
+            Ok(State {
+                horizontal: 10,
+                depth: 0,
+                aim: 0
+            })
+        );
+    }
+
+    #[test]
+    fn traces_every_position() {
+        let program = parse_program(&["repeat 2", "forward 1", "end", "down 3"], true).unwrap();
+        let mut positions = vec![];
+
+        trace(&program, Mode::Direct, |state| {
+            positions.push((state.horizontal, state.depth))
+        })
+        .unwrap();
+
+        assert_eq!(positions, [(0, 0), (1, 0), (2, 0), (2, 3)]);
+    }
+
+    #[test]
+    fn reports_overflow() {
+        let program = parse_program(&["down 9223372036854775807", "forward 2"], false).unwrap();
+
+        assert!(run(&program, Mode::Direct).is_ok());
+        assert_eq!(run(&program, Mode::Aimed), Err(OutOfRange));
+    }
+
+    #[test]
+    fn rejects_malformed_commands() {
+        let error = |lines: &[&str], extended: bool| {
+            let error = parse_program(lines, extended).unwrap_err();
+
+            (error.line, error.column, error.expected)
+        };
+
+        assert_eq!(
+            error(&["forward 1", "sideways 2"], false),
+            (2, 1, "forward, down or up".to_string())
+        );
+        assert_eq!(
+            error(&["back 2"], false),
+            (1, 1, "forward, down or up".to_string())
+        );
+        assert_eq!(
+            error(&["dive 2"], true),
+            (
+                1,
+                1,
+                "forward, down, up, back, reset-aim or repeat".to_string()
+            )
+        );
+        assert_eq!(
+            error(&["down -3"], false),
+            (1, 6, "a non-negative distance".to_string())
+        );
+        assert_eq!(
+            error(&["back -3"], true),
+            (1, 6, "a non-negative distance".to_string())
+        );
+        assert_eq!(error(&["up"], false), (1, 3, "` `".to_string()));
+        assert_eq!(error(&["up "], false), (1, 4, "an integer".to_string()));
+        assert_eq!(error(&["up 3 4"], false), (1, 5, "end of line".to_string()));
+        assert_eq!(
+            error(&["repeat 2", "up 1"], true),
+            (3, 1, "`end` of the repeat on line 1".to_string())
+        );
+        assert_eq!(error(&["end"], true).1, 1);
+    }
+
+    #[test]
+    fn renders_the_profile() {
+        let program = parse_program(&EXAMPLE, false).unwrap();
+        let profile = render_profile(&program, Mode::Direct, 16, 4).unwrap();
+        let mut lines = profile.lines();
+
+        assert_eq!(lines.next(), Some("depth 0..10, horizontal position 0..15"));
+        assert!(lines.next().unwrap().starts_with('S'));
+        assert!(lines.last().unwrap().ends_with('E'));
+    }
+}
@@ -0,0 +1,245 @@
+//! Sliding window statistics over a stream of numbers.
+//!
+//! Window sums are kept up to date as values arrive, so each value costs the same
+//! whatever the window size, and only the values of the current window are stored.
+//! Windows summing to more than an optional threshold are flagged as they complete.
+
+use std::cmp::Ordering;
+use std::collections::VecDeque;
+
+/// Counts of how window sums changed from one window to the next.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct WindowStats {
+    pub windows: usize,
+    pub increases: usize,
+    pub decreases: usize,
+    /// Most increases in a row.
+    pub longest_increasing_run: usize,
+    /// Windows whose sum is above the threshold, if one is set.
+    pub above_threshold: usize,
+}
+
+/// A full window, reported as soon as its last value arrives.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Window {
+    /// Index of the first value in the window, counting from 0.
+    pub start: usize,
+    pub sum: i64,
+    /// How the sum compares to the previous window's, or `None` for the first window.
+    pub change: Option<Ordering>,
+    /// Whether the sum is above the threshold, always `false` without one.
+    pub above_threshold: bool,
+}
+
+/// Tracks windows of a fixed size sliding over values pushed one at a time.
+#[derive(Clone, Debug)]
+pub struct WindowAnalyzer {
+    size: usize,
+    values: VecDeque<i64>,
+    pushed: usize,
+    sum: i64,
+    prev_sum: Option<i64>,
+    threshold: Option<i64>,
+    run: usize,
+    stats: WindowStats,
+}
+
+/// Computes the statistics of all windows of `size` values.
+///
+/// # Panics
+///
+/// Panics if `size` is 0.
+pub fn analyze<I: IntoIterator<Item = i64>>(values: I, size: usize) -> WindowStats {
+    let mut analyzer = WindowAnalyzer::new(size);
+
+    for value in values {
+        analyzer.push(value);
+    }
+
+    analyzer.stats()
+}
+
+/// Returns the starts (counting from 0) of the windows of `size` values
+/// that sum to more than `threshold`.
+///
+/// # Panics
+///
+/// Panics if `size` is 0.
+pub fn above_threshold<I>(values: I, size: usize, threshold: i64) -> Vec<usize>
+where
+    I: IntoIterator<Item = i64>,
+{
+    let mut analyzer = WindowAnalyzer::new(size).with_threshold(threshold);
+
+    values
+        .into_iter()
+        .filter_map(|value| analyzer.push(value))
+        .filter(|window| window.above_threshold)
+        .map(|window| window.start)
+        .collect()
+}
+
+impl WindowAnalyzer {
+    /// # Panics
+    ///
+    /// Panics if `size` is 0.
+    pub fn new(size: usize) -> Self {
+        assert!(size > 0, "Windows must hold at least one value");
+
+        Self {
+            size,
+            values: VecDeque::with_capacity(size + 1),
+            pushed: 0,
+            sum: 0,
+            prev_sum: None,
+            threshold: None,
+            run: 0,
+            stats: WindowStats::default(),
+        }
+    }
+
+    /// Flags the windows whose sum is above `threshold`.
+    pub fn with_threshold(self, threshold: i64) -> Self {
+        Self {
+            threshold: Some(threshold),
+            ..self
+        }
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Statistics of the windows seen so far.
+    pub fn stats(&self) -> WindowStats {
+        self.stats
+    }
+
+    /// Slides the window over one more value, returning the window ending with it
+    /// once enough values have arrived.
+    pub fn push(&mut self, value: i64) -> Option<Window> {
+        self.values.push_back(value);
+        self.sum += value;
+        self.pushed += 1;
+
+        if self.values.len() > self.size {
+            self.sum -= self.values.pop_front().unwrap();
+        }
+
+        if self.values.len() < self.size {
+            return None;
+        }
+
+        let change = self.prev_sum.map(|prev| self.sum.cmp(&prev));
+        let above_threshold = self.threshold.is_some_and(|threshold| self.sum > threshold);
+
+        self.stats.windows += 1;
+
+        if above_threshold {
+            self.stats.above_threshold += 1;
+        }
+
+        match change {
+            Some(Ordering::Greater) => {
+                self.stats.increases += 1;
+                self.run += 1;
+                self.stats.longest_increasing_run =
+                    std::cmp::max(self.stats.longest_increasing_run, self.run);
+            }
+            Some(Ordering::Less) => {
+                self.stats.decreases += 1;
+                self.run = 0;
+            }
+            _ => self.run = 0,
+        }
+
+        self.prev_sum = Some(self.sum);
+
+        Some(Window {
+            start: self.pushed - self.size,
+            sum: self.sum,
+            change,
+            above_threshold,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DEPTHS: [i64; 10] = [199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
+
+    #[test]
+    fn example_increases() {
+        assert_eq!(analyze(DEPTHS, 1).increases, 7);
+        assert_eq!(analyze(DEPTHS, 3).increases, 5);
+    }
+
+    #[test]
+    fn stats_of_any_window_size() {
+        let stats = analyze(DEPTHS, 3);
+
+        assert_eq!(
+            stats,
+            WindowStats {
+                windows: 8,
+                increases: 5,
+                decreases: 1,
+                longest_increasing_run: 4,
+                above_threshold: 0,
+            }
+        );
+
+        let whole = analyze(DEPTHS, DEPTHS.len());
+        assert_eq!((whole.windows, whole.increases, whole.decreases), (1, 0, 0));
+    }
+
+    #[test]
+    fn windows_report_their_start_and_sum() {
+        let mut analyzer = WindowAnalyzer::new(3);
+        let windows = DEPTHS.iter().map(|&d| analyzer.push(d)).collect::<Vec<_>>();
+
+        assert_eq!(windows[..2], [None, None]);
+        assert_eq!(
+            windows[2],
+            Some(Window {
+                start: 0,
+                sum: 607,
+                change: None,
+                above_threshold: false,
+            })
+        );
+        assert_eq!(
+            windows[4].map(|w| (w.start, w.sum, w.change)),
+            Some((2, 618, Some(Ordering::Equal)))
+        );
+    }
+
+    #[test]
+    fn flags_windows_above_the_threshold() {
+        assert_eq!(above_threshold(DEPTHS, 1, 250), [7, 8, 9]);
+        assert_eq!(above_threshold(DEPTHS, 3, 700), [5, 6, 7]);
+        assert!(above_threshold(DEPTHS, 3, 800).is_empty());
+
+        let mut analyzer = WindowAnalyzer::new(2).with_threshold(400);
+        for depth in DEPTHS {
+            analyzer.push(depth);
+        }
+        assert_eq!(analyzer.stats().above_threshold, 8);
+    }
+
+    #[test]
+    fn empty_and_short_input() {
+        assert_eq!(analyze([], 3), WindowStats::default());
+        assert_eq!(analyze([1, 2], 3), WindowStats::default());
+        assert!(above_threshold([1, 2], 3, 0).is_empty());
+        assert_eq!(analyze([1, 2, 3], 3).windows, 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "Windows must hold at least one value")]
+    fn rejects_empty_windows() {
+        WindowAnalyzer::new(0);
+    }
+}
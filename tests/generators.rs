@@ -0,0 +1,55 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+use aoc::gen::GENERATORS;
+use aoc::params::Params;
+use aoc::report::Record;
+
+/// Seed used by `aoc gen` unless given another.
+const SEED: u64 = 2021;
+
+/// Returns the path of a day's solution, built next to the runner.
+fn solution_exe(day: u32) -> PathBuf {
+    let runner = Path::new(env!("CARGO_BIN_EXE_aoc"));
+
+    runner
+        .with_file_name(format!("d{:02}", day))
+        .with_extension(std::env::consts::EXE_EXTENSION)
+}
+
+#[test]
+fn default_inputs_are_solved() {
+    for generator in GENERATORS {
+        let day = generator.day;
+        let params = Params::resolve(&generator.knobs(), []).unwrap();
+        let input = generator.generate(SEED, &params).unwrap();
+
+        let path =
+            std::env::temp_dir().join(format!("aoc-gen-day{:02}-{}.txt", day, std::process::id()));
+        fs::write(&path, input).unwrap();
+
+        let output = Command::new(solution_exe(day))
+            .arg(&path)
+            .args(["--format", "json"])
+            .stdin(Stdio::null())
+            .output()
+            .unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert!(
+            output.status.success(),
+            "day {} failed on its generated input: {}",
+            day,
+            String::from_utf8_lossy(&output.stderr)
+        );
+
+        let records: Vec<Record> = String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .map(|line| line.parse().unwrap())
+            .collect();
+        let parts: Vec<_> = records.iter().map(|r| (r.day, r.part)).collect();
+
+        assert_eq!(parts, [(day, 1), (day, 2)], "day {}", day);
+    }
+}